@@ -0,0 +1,192 @@
+use super::champion_stats::{significance, ChampionStats, ChampionStatsTracker};
+use super::protection::normalize_champion;
+use crate::api::models::{DataDragonChampions, ParticipantDto};
+use std::collections::HashMap;
+
+/// Data Dragon champion classes
+pub const CLASSES: [&str; 6] = ["Assassin", "Fighter", "Mage", "Marksman", "Support", "Tank"];
+
+/// Games with an archetype before it is reported
+pub const ARCHETYPE_MIN_GAMES: usize = 2;
+
+/// Champion -> Data Dragon tags, primary class first
+pub struct ChampionClasses {
+    tags: HashMap<String, Vec<String>>,
+}
+
+impl ChampionClasses {
+    pub fn from_data(data: &DataDragonChampions) -> Self {
+        let tags = data
+            .data
+            .values()
+            .flat_map(|info| {
+                [
+                    (normalize_champion(&info.id), info.tags.clone()),
+                    (normalize_champion(&info.name), info.tags.clone()),
+                ]
+            })
+            .collect();
+
+        ChampionClasses { tags }
+    }
+
+    pub fn primary_class(&self, champion: &str) -> Option<&str> {
+        self.tags
+            .get(&normalize_champion(champion))
+            .and_then(|tags| tags.first())
+            .map(|t| t.as_str())
+    }
+
+    pub fn has_class(&self, champion: &str, class: &str) -> bool {
+        self.tags
+            .get(&normalize_champion(champion))
+            .map(|tags| tags.iter().any(|t| t == class))
+            .unwrap_or(false)
+    }
+
+    /// Archetypes of a five-champion team, e.g. "2+ Assassins", "no Tank"
+    pub fn classify_team(&self, champions: &[&str]) -> Vec<String> {
+        let mut archetypes = Vec::new();
+
+        for class in CLASSES {
+            let primary = champions
+                .iter()
+                .filter(|c| self.primary_class(c) == Some(class))
+                .count();
+            if primary >= 2 {
+                archetypes.push(format!("2+ {}s", class));
+            }
+        }
+
+        if !champions.iter().any(|c| self.has_class(c, "Tank")) {
+            archetypes.push("no Tank".to_string());
+        }
+
+        archetypes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TeamSide {
+    Ally,
+    Enemy,
+}
+
+impl TeamSide {
+    pub fn label(&self) -> &str {
+        match self {
+            TeamSide::Ally => "your team",
+            TeamSide::Enemy => "enemy team",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArchetypeStats {
+    pub side: TeamSide,
+    pub archetype: String,
+    pub games: usize,
+    pub wins: usize,
+}
+
+impl ArchetypeStats {
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+
+    pub fn significance(&self, baseline_win_rate: f64) -> f64 {
+        significance(self.wins, self.games, baseline_win_rate)
+    }
+}
+
+/// Tracks team comp archetypes and enemy classes
+pub struct ArchetypeTracker {
+    archetypes: HashMap<(TeamSide, String), ArchetypeStats>,
+    classes: ChampionStatsTracker,
+}
+
+impl ArchetypeTracker {
+    pub fn new() -> Self {
+        ArchetypeTracker {
+            archetypes: HashMap::new(),
+            classes: ChampionStatsTracker::new(),
+        }
+    }
+
+    pub fn add_game(
+        &mut self,
+        player: &ParticipantDto,
+        participants: &[ParticipantDto],
+        classes: &ChampionClasses,
+        recency_weight: f64,
+        match_id: &str,
+    ) {
+        for side in [TeamSide::Ally, TeamSide::Enemy] {
+            let team: Vec<&str> = participants
+                .iter()
+                .filter(|p| (p.team_id == player.team_id) == (side == TeamSide::Ally))
+                .map(|p| p.champion_name.as_str())
+                .collect();
+
+            for archetype in classes.classify_team(&team) {
+                let entry = self
+                    .archetypes
+                    .entry((side, archetype.clone()))
+                    .or_insert_with(|| ArchetypeStats {
+                        side,
+                        archetype,
+                        games: 0,
+                        wins: 0,
+                    });
+                entry.games += 1;
+                if player.win {
+                    entry.wins += 1;
+                }
+            }
+
+            if side == TeamSide::Enemy {
+                // Each class counts once per game, so frequency stays a share of games
+                let mut seen: Vec<&str> = team.iter().filter_map(|c| classes.primary_class(c)).collect();
+                seen.sort();
+                seen.dedup();
+                for class in seen {
+                    self.classes.add_champion_encounter(
+                        class.to_string(),
+                        player.win,
+                        recency_weight,
+                        "",
+                        1.0,
+                        match_id,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Archetypes seen at least `ARCHETYPE_MIN_GAMES` times, furthest from
+    /// the player's usual win rate first
+    pub fn get_archetypes(&self, baseline_win_rate: f64) -> Vec<ArchetypeStats> {
+        let mut archetypes: Vec<ArchetypeStats> = self
+            .archetypes
+            .values()
+            .filter(|a| a.games >= ARCHETYPE_MIN_GAMES)
+            .cloned()
+            .collect();
+        archetypes.sort_by(|a, b| {
+            b.significance(baseline_win_rate)
+                .abs()
+                .partial_cmp(&a.significance(baseline_win_rate).abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        archetypes
+    }
+
+    /// Enemy classes as stats, so they can be scored like champions
+    pub fn class_stats(&self) -> Vec<ChampionStats> {
+        self.classes.get_stats()
+    }
+}
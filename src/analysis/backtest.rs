@@ -0,0 +1,136 @@
+use super::champion_stats::ChampionStatsTracker;
+use super::protection::ProtectList;
+use super::recency::decay_weight;
+use super::recommender::BanRecommender;
+use super::scoring::{ScoringContext, ScoringStrategy};
+use crate::cache::CachedMatch;
+use chrono::{DateTime, Duration, Utc};
+
+/// Games needed before the first game is evaluated
+pub const MIN_HISTORY: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct BacktestSettings {
+    pub top_n: usize,
+    pub min_games: usize,
+    pub half_life: Duration,
+    pub decay_all: bool,
+    pub protected: ProtectList,
+    /// Also protect the player's N most played champions before each game
+    pub protect_top: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct BacktestResult {
+    pub strategy: String,
+    pub description: String,
+    pub games_evaluated: usize,
+    pub losses_evaluated: usize,
+    /// Losses where an enemy champion was among the top-N bans
+    pub loss_hits: usize,
+    /// Games (won or lost) where an enemy champion was among the top-N bans
+    pub games_with_hit: usize,
+    /// Estimated change in overall win rate had the bans been used
+    pub estimated_win_rate_delta: f64,
+}
+
+impl BacktestResult {
+    pub fn loss_hit_rate(&self) -> f64 {
+        if self.losses_evaluated == 0 {
+            0.0
+        } else {
+            self.loss_hits as f64 / self.losses_evaluated as f64
+        }
+    }
+
+    pub fn coverage(&self) -> f64 {
+        if self.games_evaluated == 0 {
+            0.0
+        } else {
+            self.games_with_hit as f64 / self.games_evaluated as f64
+        }
+    }
+}
+
+/// Replay `matches` oldest first, recommending bans for each game from the
+/// games before it only. Entries without a known game end time are skipped,
+/// since their timestamp is when they were fetched, not when they were played.
+///
+/// The win-rate estimate assumes that whenever a recommended ban was on the
+/// enemy team, banning it would have turned the game into an average game
+/// (your win rate so far) instead of its actual result. Banning a champion you
+/// went on to beat therefore counts against the strategy.
+pub fn run_backtest(
+    matches: &[CachedMatch],
+    strategy: &dyn ScoringStrategy,
+    settings: &BacktestSettings,
+) -> BacktestResult {
+    let mut history: Vec<(&CachedMatch, DateTime<Utc>)> = matches
+        .iter()
+        .filter_map(|m| m.ended_at.map(|ended_at| (m, ended_at)))
+        .collect();
+    history.sort_by_key(|(_, ended_at)| *ended_at);
+
+    let mut result = BacktestResult {
+        strategy: strategy.name().to_string(),
+        description: strategy.description(),
+        games_evaluated: 0,
+        losses_evaluated: 0,
+        loss_hits: 0,
+        games_with_hit: 0,
+        estimated_win_rate_delta: 0.0,
+    };
+    let mut win_rate_delta_total = 0.0;
+
+    for (idx, (game, game_ended_at)) in history.iter().enumerate().skip(MIN_HISTORY) {
+        let prior = &history[..idx];
+
+        let mut tracker = ChampionStatsTracker::new();
+        let mut games = Vec::with_capacity(prior.len());
+        for (past, past_ended_at) in prior {
+            let weight = decay_weight(*past_ended_at, *game_ended_at, settings.half_life);
+            games.push((past.won, weight));
+            for enemy in &past.enemies {
+                tracker.add_champion_encounter(enemy.clone(), past.won, weight, "", 1.0, &past.id);
+            }
+        }
+
+        let mut protected = settings.protected.clone();
+        let played: Vec<String> = prior.iter().map(|(m, _)| m.champion.clone()).collect();
+        protected.add_top_played(&played, settings.protect_top);
+
+        let stats = tracker.get_stats();
+        let ctx = ScoringContext::new(&stats, &games, settings.decay_all);
+        let (bannable, _) = BanRecommender::partition_protected(stats, &protected);
+        let bans = BanRecommender::get_recommendations(
+            bannable,
+            strategy,
+            &ctx,
+            settings.top_n,
+            settings.min_games,
+        );
+
+        let hit = bans
+            .iter()
+            .any(|ban| game.enemies.contains(&ban.champion_name));
+
+        result.games_evaluated += 1;
+        if !game.won {
+            result.losses_evaluated += 1;
+            if hit {
+                result.loss_hits += 1;
+            }
+        }
+        if hit {
+            result.games_with_hit += 1;
+            let actual = if game.won { 1.0 } else { 0.0 };
+            win_rate_delta_total += ctx.baseline_win_rate() - actual;
+        }
+    }
+
+    if result.games_evaluated > 0 {
+        result.estimated_win_rate_delta = win_rate_delta_total / result.games_evaluated as f64;
+    }
+
+    result
+}
@@ -0,0 +1,194 @@
+use super::lane_diffs::{LaneDiff, LaneDiffs};
+use super::metrics::GameMetrics;
+use super::scoring::PRIOR_GAMES;
+use crate::api::models::ParticipantDto;
+use std::collections::HashMap;
+
+/// Games needed before a champion gets a keep/drop verdict
+pub const MIN_VERDICT_GAMES: usize = 3;
+
+/// Games compared on each side of the trend
+const TREND_WINDOW: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct PoolGame {
+    pub won: bool,
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub cs_per_min: f64,
+    pub damage_share: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PoolVerdict {
+    Keep,
+    Watch,
+    Drop,
+}
+
+impl PoolVerdict {
+    pub fn label(&self) -> &str {
+        match self {
+            PoolVerdict::Keep => "Keep",
+            PoolVerdict::Watch => "Watch",
+            PoolVerdict::Drop => "Drop",
+        }
+    }
+}
+
+/// The player's own results on one champion
+#[derive(Debug, Clone)]
+pub struct ChampionPoolStats {
+    pub champion: String,
+    pub games: Vec<PoolGame>, // most recent first
+    pub lane: LaneDiffs,      // only filled from timelines
+}
+
+impl ChampionPoolStats {
+    pub fn new(champion: String) -> Self {
+        ChampionPoolStats {
+            champion,
+            games: Vec::new(),
+            lane: LaneDiffs::default(),
+        }
+    }
+
+    pub fn games_played(&self) -> usize {
+        self.games.len()
+    }
+
+    pub fn wins(&self) -> usize {
+        self.games.iter().filter(|g| g.won).count()
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        win_rate(&self.games)
+    }
+
+    /// Win rate shrunk toward the player's overall win rate
+    pub fn adjusted_win_rate(&self, baseline_win_rate: f64) -> f64 {
+        (self.wins() as f64 + baseline_win_rate * PRIOR_GAMES)
+            / (self.games_played() as f64 + PRIOR_GAMES)
+    }
+
+    /// (kills + assists) / deaths over all games, with deaths floored at 1
+    pub fn kda(&self) -> f64 {
+        let kills: i32 = self.games.iter().map(|g| g.kills).sum();
+        let deaths: i32 = self.games.iter().map(|g| g.deaths).sum();
+        let assists: i32 = self.games.iter().map(|g| g.assists).sum();
+        (kills + assists) as f64 / deaths.max(1) as f64
+    }
+
+    pub fn avg_cs_per_min(&self) -> f64 {
+        average(self.games.iter().map(|g| g.cs_per_min))
+    }
+
+    pub fn avg_damage_share(&self) -> f64 {
+        average(self.games.iter().map(|g| g.damage_share))
+    }
+
+    /// Results of the last `count` games, most recent first (e.g. "WWLWL")
+    pub fn recent_form(&self, count: usize) -> String {
+        self.games
+            .iter()
+            .take(count)
+            .map(|g| if g.won { 'W' } else { 'L' })
+            .collect()
+    }
+
+    /// Win rate of the latest games minus the ones before them,
+    /// `None` until there are enough games on both sides
+    pub fn trend(&self) -> Option<f64> {
+        let window = TREND_WINDOW.min(self.games.len() / 2);
+        if window < 2 {
+            return None;
+        }
+
+        let recent = &self.games[..window];
+        let older = &self.games[window..(window * 2)];
+        Some(win_rate(recent) - win_rate(older))
+    }
+
+    pub fn verdict(&self, baseline_win_rate: f64) -> PoolVerdict {
+        if self.games_played() < MIN_VERDICT_GAMES {
+            return PoolVerdict::Watch;
+        }
+
+        let adjusted = self.adjusted_win_rate(baseline_win_rate);
+        let improving = self.trend().map(|t| t > 0.0).unwrap_or(false);
+
+        if adjusted >= baseline_win_rate {
+            PoolVerdict::Keep
+        } else if adjusted < baseline_win_rate - 0.05 && !improving {
+            PoolVerdict::Drop
+        } else {
+            PoolVerdict::Watch
+        }
+    }
+}
+
+fn win_rate(games: &[PoolGame]) -> f64 {
+    if games.is_empty() {
+        0.0
+    } else {
+        games.iter().filter(|g| g.won).count() as f64 / games.len() as f64
+    }
+}
+
+fn average(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
+
+/// Tracks the champions the player played (feed games most recent first)
+pub struct ChampionPoolTracker {
+    champions: HashMap<String, ChampionPoolStats>,
+}
+
+impl ChampionPoolTracker {
+    pub fn new() -> Self {
+        ChampionPoolTracker {
+            champions: HashMap::new(),
+        }
+    }
+
+    pub fn add_game(&mut self, player: &ParticipantDto, metrics: &GameMetrics) {
+        let game = PoolGame {
+            won: player.win,
+            kills: player.kills,
+            deaths: player.deaths,
+            assists: player.assists,
+            cs_per_min: metrics.cs_per_min,
+            damage_share: metrics.damage_share,
+        };
+
+        self.champions
+            .entry(player.champion_name.clone())
+            .or_insert_with(|| ChampionPoolStats::new(player.champion_name.clone()))
+            .games
+            .push(game);
+    }
+
+    /// Timeline lane diffs for a game already added with `add_game`
+    pub fn add_lane_diffs(&mut self, player: &ParticipantDto, diffs: &[Option<LaneDiff>; 3]) {
+        if let Some(stats) = self.champions.get_mut(&player.champion_name) {
+            stats.lane.add(diffs, player.win);
+        }
+    }
+
+    /// Most played first
+    pub fn get_pool(&self) -> Vec<ChampionPoolStats> {
+        let mut pool: Vec<ChampionPoolStats> = self.champions.values().cloned().collect();
+        pool.sort_by(|a, b| {
+            b.games_played()
+                .cmp(&a.games_played())
+                .then_with(|| b.win_rate().partial_cmp(&a.win_rate()).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        pool
+    }
+}
@@ -1,3 +1,4 @@
+use super::protection::normalize_champion;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -5,7 +6,13 @@ pub struct ChampionStats {
     pub name: String,
     pub times_faced: usize,
     pub wins_against: usize,
-    pub recency_score: f64, // weighted by match index
+    pub recency_score: f64, // sum of per-game time-decay weights
+    pub decayed_faced: f64,   // times_faced, each game weighted by recency
+    pub decayed_wins: f64,    // wins_against, each game weighted by recency
+    pub role_counts: HashMap<String, usize>, // teamPosition -> games
+    pub role_weight_sum: f64,
+    pub matches: Vec<(String, bool)>, // (match ID, won) for every encounter
+    pub deaths_caused: usize, // times this champion killed the player (timelines only)
 }
 
 impl ChampionStats {
@@ -15,9 +22,36 @@ impl ChampionStats {
             times_faced: 0,
             wins_against: 0,
             recency_score: 0.0,
+            decayed_faced: 0.0,
+            decayed_wins: 0.0,
+            role_counts: HashMap::new(),
+            role_weight_sum: 0.0,
+            matches: Vec::new(),
+            deaths_caused: 0,
         }
     }
 
+    /// Average role weight over all encounters (1.0 when never weighted)
+    pub fn role_factor(&self) -> f64 {
+        if self.times_faced == 0 {
+            1.0
+        } else {
+            self.role_weight_sum / self.times_faced as f64
+        }
+    }
+
+    /// Roles this champion was played in, most common first
+    pub fn roles(&self) -> Vec<(String, usize)> {
+        let mut roles: Vec<(String, usize)> = self
+            .role_counts
+            .iter()
+            .filter(|(role, _)| !role.is_empty())
+            .map(|(role, count)| (role.clone(), *count))
+            .collect();
+        roles.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        roles
+    }
+
     pub fn win_rate(&self) -> f64 {
         if self.times_faced == 0 {
             0.0
@@ -26,6 +60,22 @@ impl ChampionStats {
         }
     }
 
+    /// Win rate shrunk toward `prior` as if `prior_games` extra games had
+    /// been played at that rate. Small samples stay close to the prior.
+    pub fn shrunk_win_rate(&self, prior: f64, prior_games: f64) -> f64 {
+        shrink(self.wins_against as f64, self.times_faced as f64, prior, prior_games)
+    }
+
+    /// Same as `shrunk_win_rate`, with every game weighted by recency
+    pub fn decayed_shrunk_win_rate(&self, prior: f64, prior_games: f64) -> f64 {
+        shrink(self.decayed_wins, self.decayed_faced, prior, prior_games)
+    }
+
+    /// Wilson score interval for the win rate (`z` = 1.96 for 95%)
+    pub fn wilson_interval(&self, z: f64) -> (f64, f64) {
+        wilson_interval(self.wins_against, self.times_faced, z)
+    }
+
     pub fn frequency(&self, total_games: usize) -> f64 {
         if total_games == 0 {
             0.0
@@ -35,6 +85,43 @@ impl ChampionStats {
     }
 }
 
+/// Wilson score interval for `wins` out of `games`
+pub fn wilson_interval(wins: usize, games: usize, z: f64) -> (f64, f64) {
+    if games == 0 {
+        return (0.0, 1.0);
+    }
+
+    let n = games as f64;
+    let p = wins as f64 / n;
+    let z2 = z * z;
+    let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let margin = (z / (1.0 + z2 / n)) * ((p * (1.0 - p) / n) + z2 / (4.0 * n * n)).sqrt();
+
+    ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+/// How many standard errors `wins / games` is from `baseline_win_rate`
+/// (positive = better than usual)
+pub fn significance(wins: usize, games: usize, baseline_win_rate: f64) -> f64 {
+    if games == 0 {
+        return 0.0;
+    }
+
+    let baseline = baseline_win_rate.clamp(0.05, 0.95);
+    let std_error = (baseline * (1.0 - baseline) / games as f64).sqrt();
+    (wins as f64 / games as f64 - baseline) / std_error
+}
+
+/// `wins / games` shrunk toward `prior` by `prior_games` virtual games
+pub fn shrink(wins: f64, games: f64, prior: f64, prior_games: f64) -> f64 {
+    let total = games + prior_games;
+    if total <= 0.0 {
+        prior
+    } else {
+        (wins + prior * prior_games) / total
+    }
+}
+
 pub struct ChampionStatsTracker {
     stats: HashMap<String, ChampionStats>,
     ally_stats: HashMap<String, ChampionStats>,
@@ -53,16 +140,32 @@ impl ChampionStatsTracker {
         champion_name: String,
         won_against: bool,
         recency_weight: f64,
+        role: &str,
+        role_weight: f64,
+        match_id: &str,
     ) {
         let entry = self.stats.entry(champion_name.clone()).or_insert_with(|| {
             ChampionStats::new(champion_name)
         });
 
         entry.times_faced += 1;
+        entry.decayed_faced += recency_weight;
         if won_against {
             entry.wins_against += 1;
+            entry.decayed_wins += recency_weight;
         }
         entry.recency_score += recency_weight;
+        *entry.role_counts.entry(role.to_string()).or_insert(0) += 1;
+        entry.role_weight_sum += role_weight;
+        entry.matches.push((match_id.to_string(), won_against));
+    }
+
+    /// Count a death of the player to `champion_name`, an enemy already
+    /// added with `add_champion_encounter`
+    pub fn add_kill_on_player(&mut self, champion_name: &str) {
+        if let Some(entry) = self.stats.get_mut(champion_name) {
+            entry.deaths_caused += 1;
+        }
     }
 
     pub fn add_ally_encounter(
@@ -76,10 +179,13 @@ impl ChampionStatsTracker {
         });
 
         entry.times_faced += 1;
+        entry.decayed_faced += recency_weight;
         if match_won {
             entry.wins_against += 1;
+            entry.decayed_wins += recency_weight;
         }
         entry.recency_score += recency_weight;
+        entry.role_weight_sum += 1.0;
     }
 
     pub fn get_stats(&self) -> Vec<ChampionStats> {
@@ -95,6 +201,12 @@ impl ChampionStatsTracker {
         self.stats.get(name).cloned()
     }
 
+    /// Like `get_champion`, ignoring case, spaces and punctuation
+    pub fn find_champion(&self, name: &str) -> Option<&ChampionStats> {
+        let key = normalize_champion(name);
+        self.stats.values().find(|s| normalize_champion(&s.name) == key)
+    }
+
     #[allow(dead_code)]
     pub fn get_ally(&self, name: &str) -> Option<ChampionStats> {
         self.ally_stats.get(name).cloned()
@@ -0,0 +1,99 @@
+use super::champion_stats::{shrink, wilson_interval};
+use super::matchups::MatchupTracker;
+use super::picks::PickTracker;
+use super::recommender::CONFIDENCE_Z;
+use super::scoring::PRIOR_GAMES;
+use std::collections::HashMap;
+
+/// The player's record on one champion against one enemy
+#[derive(Debug, Clone)]
+pub struct CounterEvidence {
+    pub enemy: String,
+    pub games: usize,
+    pub wins: usize,
+    /// From direct lane matchups rather than team-level results
+    pub lane: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CounterPick {
+    pub champion: String,
+    pub evidence: Vec<CounterEvidence>,
+    pub games: usize, // distinct games against any of the enemies
+    pub wins: usize,
+    pub adjusted_win_rate: f64,
+    pub confidence_interval: (f64, f64),
+}
+
+/// Rank the player's champions by how they did against `enemies`.
+///
+/// Lane matchups are used when the player laned against the enemy on that
+/// champion; otherwise games with the enemy anywhere on the other team count.
+/// The pooled record counts each game once, even when several of the enemies
+/// were in it, and is shrunk toward `baseline_win_rate`.
+pub fn suggest_counters(
+    enemies: &[String],
+    picks: &PickTracker,
+    matchups: &MatchupTracker,
+    baseline_win_rate: f64,
+) -> Vec<CounterPick> {
+    let mut counters: Vec<CounterPick> = picks
+        .all_picks()
+        .into_iter()
+        .filter_map(|pick| {
+            let evidence: Vec<CounterEvidence> = enemies
+                .iter()
+                .filter_map(|enemy| {
+                    if let Some(m) = matchups.find_matchup(&pick.champion, enemy) {
+                        return Some(CounterEvidence {
+                            enemy: m.opponent_champion.clone(),
+                            games: m.games,
+                            wins: m.wins,
+                            lane: true,
+                        });
+                    }
+
+                    pick.tracker.find_champion(enemy).map(|s| CounterEvidence {
+                        enemy: s.name.clone(),
+                        games: s.times_faced,
+                        wins: s.wins_against,
+                        lane: false,
+                    })
+                })
+                .collect();
+
+            if evidence.is_empty() {
+                return None;
+            }
+
+            // Every lane matchup game is also a team-level game, so the
+            // distinct games come from the match IDs of the team-level stats
+            let mut pooled: HashMap<&str, bool> = HashMap::new();
+            for enemy in enemies {
+                if let Some(stats) = pick.tracker.find_champion(enemy) {
+                    for (match_id, won) in &stats.matches {
+                        pooled.insert(match_id.as_str(), *won);
+                    }
+                }
+            }
+            let games = pooled.len();
+            let wins = pooled.values().filter(|won| **won).count();
+            Some(CounterPick {
+                champion: pick.champion.clone(),
+                evidence,
+                games,
+                wins,
+                adjusted_win_rate: shrink(wins as f64, games as f64, baseline_win_rate, PRIOR_GAMES),
+                confidence_interval: wilson_interval(wins, games, CONFIDENCE_Z),
+            })
+        })
+        .collect();
+
+    counters.sort_by(|a, b| {
+        b.adjusted_win_rate
+            .partial_cmp(&a.adjusted_win_rate)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| b.games.cmp(&a.games))
+    });
+    counters
+}
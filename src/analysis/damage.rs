@@ -0,0 +1,132 @@
+use super::archetypes::TeamSide;
+use super::champion_stats::significance;
+use crate::api::models::ParticipantDto;
+use std::collections::HashMap;
+
+/// Physical share of champion damage at or above which a team is AD-heavy
+const AD_HEAVY_SHARE: f64 = 0.65;
+
+/// Magic share of champion damage at or above which a team is AP-heavy
+const AP_HEAVY_SHARE: f64 = 0.55;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DamageProfile {
+    AdHeavy,
+    Mixed,
+    ApHeavy,
+}
+
+impl DamageProfile {
+    pub const ALL: [DamageProfile; 3] = [DamageProfile::AdHeavy, DamageProfile::Mixed, DamageProfile::ApHeavy];
+
+    pub fn label(&self) -> &str {
+        match self {
+            DamageProfile::AdHeavy => "AD-heavy",
+            DamageProfile::Mixed => "Mixed",
+            DamageProfile::ApHeavy => "AP-heavy",
+        }
+    }
+
+    /// Classify a team by its damage dealt to champions
+    pub fn classify(team: &[&ParticipantDto]) -> Option<Self> {
+        let physical: i64 = team.iter().map(|p| p.physical_damage_dealt_to_champions).sum();
+        let magic: i64 = team.iter().map(|p| p.magic_damage_dealt_to_champions).sum();
+        let true_damage: i64 = team.iter().map(|p| p.true_damage_dealt_to_champions).sum();
+        let total = physical + magic + true_damage;
+        if total <= 0 {
+            return None;
+        }
+
+        let ad_share = physical as f64 / total as f64;
+        let ap_share = magic as f64 / total as f64;
+        Some(if ad_share >= AD_HEAVY_SHARE {
+            DamageProfile::AdHeavy
+        } else if ap_share >= AP_HEAVY_SHARE {
+            DamageProfile::ApHeavy
+        } else {
+            DamageProfile::Mixed
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Record {
+    pub games: usize,
+    pub wins: usize,
+}
+
+impl Record {
+    pub fn add(&mut self, won: bool) {
+        self.games += 1;
+        if won {
+            self.wins += 1;
+        }
+    }
+
+    pub fn losses(&self) -> usize {
+        self.games - self.wins
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+
+    pub fn significance(&self, baseline_win_rate: f64) -> f64 {
+        significance(self.wins, self.games, baseline_win_rate)
+    }
+}
+
+/// Tracks results by the damage mix of both teams
+pub struct DamageTracker {
+    by_side: HashMap<(TeamSide, DamageProfile), Record>,
+    by_matchup: HashMap<(DamageProfile, DamageProfile), Record>, // (yours, enemy)
+}
+
+impl DamageTracker {
+    pub fn new() -> Self {
+        DamageTracker {
+            by_side: HashMap::new(),
+            by_matchup: HashMap::new(),
+        }
+    }
+
+    pub fn add_game(&mut self, player: &ParticipantDto, participants: &[ParticipantDto]) {
+        let (ours, theirs): (Vec<&ParticipantDto>, Vec<&ParticipantDto>) =
+            participants.iter().partition(|p| p.team_id == player.team_id);
+
+        let (Some(own_profile), Some(enemy_profile)) =
+            (DamageProfile::classify(&ours), DamageProfile::classify(&theirs))
+        else {
+            return;
+        };
+
+        self.by_side
+            .entry((TeamSide::Ally, own_profile))
+            .or_default()
+            .add(player.win);
+        self.by_side
+            .entry((TeamSide::Enemy, enemy_profile))
+            .or_default()
+            .add(player.win);
+        self.by_matchup
+            .entry((own_profile, enemy_profile))
+            .or_default()
+            .add(player.win);
+    }
+
+    pub fn record(&self, side: TeamSide, profile: DamageProfile) -> Record {
+        self.by_side.get(&(side, profile)).copied().unwrap_or_default()
+    }
+
+    pub fn matchup(&self, own: DamageProfile, enemy: DamageProfile) -> Record {
+        self.by_matchup.get(&(own, enemy)).copied().unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_side.is_empty()
+    }
+}
@@ -0,0 +1,231 @@
+use crate::api::models::{EventDto, ParticipantDto, TimelineDto};
+use std::collections::HashMap;
+
+/// Summoner's Rift spans roughly 0..14870 on both axes, blue base at (0, 0)
+const MAP_SIZE: i32 = 14870;
+
+/// Minimap heatmap resolution (terminal cells are about twice as tall as wide)
+pub const HEATMAP_COLS: usize = 32;
+pub const HEATMAP_ROWS: usize = 16;
+
+/// Killer label for deaths to towers, minions and monsters
+pub const EXECUTED: &str = "Executed";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamePhase {
+    Early,
+    Mid,
+    Late,
+}
+
+impl GamePhase {
+    pub const ALL: [GamePhase; 3] = [GamePhase::Early, GamePhase::Mid, GamePhase::Late];
+
+    pub fn from_timestamp(timestamp_ms: i64) -> Self {
+        match timestamp_ms / 60_000 {
+            m if m < 14 => GamePhase::Early,
+            m if m < 25 => GamePhase::Mid,
+            _ => GamePhase::Late,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            GamePhase::Early => "Early (0-14 min)",
+            GamePhase::Mid => "Mid (14-25 min)",
+            GamePhase::Late => "Late (25+ min)",
+        }
+    }
+}
+
+/// Map area seen from the player's side (own base bottom-left)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapRegion {
+    TopLane,
+    MidLane,
+    BotLane,
+    River,
+    OwnJungle,
+    EnemyJungle,
+    OwnBase,
+    EnemyBase,
+}
+
+impl MapRegion {
+    /// `x` and `y` must already be in the player's perspective
+    pub fn classify(x: i32, y: i32) -> Self {
+        if x < 4500 && y < 4500 {
+            MapRegion::OwnBase
+        } else if x > MAP_SIZE - 4500 && y > MAP_SIZE - 4500 {
+            MapRegion::EnemyBase
+        } else if x < 2500 || y > MAP_SIZE - 2500 {
+            MapRegion::TopLane
+        } else if y < 2500 || x > MAP_SIZE - 2500 {
+            MapRegion::BotLane
+        } else if (x - y).abs() < 1500 {
+            MapRegion::MidLane
+        } else if (x + y - MAP_SIZE).abs() < 1500 {
+            MapRegion::River
+        } else if x + y < MAP_SIZE {
+            MapRegion::OwnJungle
+        } else {
+            MapRegion::EnemyJungle
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            MapRegion::TopLane => "Top lane",
+            MapRegion::MidLane => "Mid lane",
+            MapRegion::BotLane => "Bot lane",
+            MapRegion::River => "River",
+            MapRegion::OwnJungle => "Your jungle",
+            MapRegion::EnemyJungle => "Enemy jungle",
+            MapRegion::OwnBase => "Your base",
+            MapRegion::EnemyBase => "Enemy base",
+        }
+    }
+}
+
+/// Flip red side coordinates so the player's base is always bottom-left
+fn to_player_perspective(x: i32, y: i32, team_id: i32) -> (i32, i32) {
+    if team_id == 200 {
+        (MAP_SIZE - x, MAP_SIZE - y)
+    } else {
+        (x, y)
+    }
+}
+
+/// Where and when the player died, and who to
+pub struct DeathTracker {
+    games: usize,
+    by_phase: HashMap<GamePhase, usize>,
+    by_region: HashMap<MapRegion, usize>,
+    by_killer: HashMap<String, usize>,
+    heatmap: [[usize; HEATMAP_COLS]; HEATMAP_ROWS],
+}
+
+impl DeathTracker {
+    pub fn new() -> Self {
+        DeathTracker {
+            games: 0,
+            by_phase: HashMap::new(),
+            by_region: HashMap::new(),
+            by_killer: HashMap::new(),
+            heatmap: [[0; HEATMAP_COLS]; HEATMAP_ROWS],
+        }
+    }
+
+    /// Record the player's deaths in one game; returns the killer champion
+    /// of each death (executions excluded)
+    pub fn add_game(
+        &mut self,
+        timeline: &TimelineDto,
+        player: &ParticipantDto,
+        participants: &[ParticipantDto],
+    ) -> Vec<String> {
+        let Some(player_id) = timeline.participant_id(&player.puuid) else {
+            return Vec::new();
+        };
+        self.games += 1;
+
+        let champion_of = |id: i32| -> Option<String> {
+            let puuid = &timeline
+                .info
+                .participants
+                .iter()
+                .find(|p| p.participant_id == id)?
+                .puuid;
+            participants
+                .iter()
+                .find(|p| &p.puuid == puuid)
+                .map(|p| p.champion_name.clone())
+        };
+
+        let mut killers = Vec::new();
+        for event in timeline.events() {
+            let EventDto::ChampionKill { timestamp, killer_id, victim_id, position, .. } = event else {
+                continue;
+            };
+            if *victim_id != player_id {
+                continue;
+            }
+
+            *self.by_phase.entry(GamePhase::from_timestamp(*timestamp)).or_insert(0) += 1;
+
+            if let Some(pos) = position {
+                let (x, y) = to_player_perspective(pos.x, pos.y, player.team_id);
+                *self.by_region.entry(MapRegion::classify(x, y)).or_insert(0) += 1;
+
+                let col = (x.clamp(0, MAP_SIZE - 1) as usize * HEATMAP_COLS) / MAP_SIZE as usize;
+                let row = (y.clamp(0, MAP_SIZE - 1) as usize * HEATMAP_ROWS) / MAP_SIZE as usize;
+                self.heatmap[HEATMAP_ROWS - 1 - row][col] += 1;
+            }
+
+            match champion_of(*killer_id) {
+                Some(killer) => {
+                    *self.by_killer.entry(killer.clone()).or_insert(0) += 1;
+                    killers.push(killer);
+                }
+                None => *self.by_killer.entry(EXECUTED.to_string()).or_insert(0) += 1,
+            }
+        }
+
+        killers
+    }
+
+    pub fn games(&self) -> usize {
+        self.games
+    }
+
+    pub fn total_deaths(&self) -> usize {
+        self.by_phase.values().sum()
+    }
+
+    pub fn by_phase(&self) -> Vec<(GamePhase, usize)> {
+        GamePhase::ALL
+            .iter()
+            .map(|phase| (*phase, self.by_phase.get(phase).copied().unwrap_or(0)))
+            .collect()
+    }
+
+    /// Most deaths first
+    pub fn by_region(&self) -> Vec<(MapRegion, usize)> {
+        let mut regions: Vec<(MapRegion, usize)> =
+            self.by_region.iter().map(|(r, c)| (*r, *c)).collect();
+        regions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.label().cmp(b.0.label())));
+        regions
+    }
+
+    /// Most deaths first
+    pub fn by_killer(&self) -> Vec<(String, usize)> {
+        let mut killers: Vec<(String, usize)> =
+            self.by_killer.iter().map(|(k, c)| (k.clone(), *c)).collect();
+        killers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        killers
+    }
+
+    /// Ban hints for enemy champions behind at least `min_share` of deaths
+    pub fn killer_ban_hints(&self, min_share: f64) -> Vec<(String, String)> {
+        let total = self.total_deaths();
+        self.by_killer()
+            .into_iter()
+            .filter(|(killer, count)| {
+                killer != EXECUTED && *count >= 2 && *count as f64 >= total as f64 * min_share
+            })
+            .map(|(killer, count)| {
+                let note = format!(
+                    "killed you {} times ({:.0}% of your deaths)",
+                    count,
+                    count as f64 / total as f64 * 100.0
+                );
+                (killer, note)
+            })
+            .collect()
+    }
+
+    /// Death counts per minimap cell, top row first
+    pub fn heatmap(&self) -> &[[usize; HEATMAP_COLS]; HEATMAP_ROWS] {
+        &self.heatmap
+    }
+}
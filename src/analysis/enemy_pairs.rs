@@ -0,0 +1,100 @@
+use super::synergy::{PairKind, PairStats, PAIR_MIN_GAMES};
+use crate::api::models::ParticipantDto;
+use std::collections::HashMap;
+
+/// Standard errors below the player's usual win rate for a pair to count as dangerous
+const DANGER_SIGNIFICANCE: f64 = -1.0;
+
+/// Enemy champion pairs that play together: bot lane and jungle + mid
+pub struct EnemyPairTracker {
+    pairs: HashMap<(PairKind, String, String), PairStats>,
+}
+
+impl EnemyPairTracker {
+    pub fn new() -> Self {
+        EnemyPairTracker {
+            pairs: HashMap::new(),
+        }
+    }
+
+    fn add_pair(&mut self, kind: PairKind, first: &str, second: &str, won: bool) {
+        let entry = self
+            .pairs
+            .entry((kind, first.to_string(), second.to_string()))
+            .or_insert_with(|| PairStats::new(kind, first.to_string(), second.to_string()));
+
+        entry.games += 1;
+        if won {
+            entry.wins += 1;
+        }
+    }
+
+    pub fn add_game(&mut self, player: &ParticipantDto, participants: &[ParticipantDto]) {
+        let enemy_in = |position: &str| {
+            participants
+                .iter()
+                .find(|p| p.team_id != player.team_id && p.team_position == position)
+        };
+
+        if let (Some(adc), Some(support)) = (enemy_in("BOTTOM"), enemy_in("UTILITY")) {
+            self.add_pair(PairKind::EnemyBotLane, &adc.champion_name, &support.champion_name, player.win);
+        }
+        if let (Some(jungle), Some(mid)) = (enemy_in("JUNGLE"), enemy_in("MIDDLE")) {
+            self.add_pair(PairKind::EnemyJungleMid, &jungle.champion_name, &mid.champion_name, player.win);
+        }
+    }
+
+    /// Pairs met at least `PAIR_MIN_GAMES` times, most losses first
+    pub fn get_pairs(&self) -> Vec<PairStats> {
+        let mut pairs: Vec<PairStats> = self
+            .pairs
+            .values()
+            .filter(|p| p.games >= PAIR_MIN_GAMES)
+            .cloned()
+            .collect();
+        pairs.sort_by(|a, b| {
+            (b.games - b.wins)
+                .cmp(&(a.games - a.wins))
+                .then_with(|| a.win_rate().partial_cmp(&b.win_rate()).unwrap_or(std::cmp::Ordering::Equal))
+                .then_with(|| b.games.cmp(&a.games))
+        });
+        pairs
+    }
+
+    /// Ban hints for both halves of every dangerous pair: champion -> note
+    pub fn dangerous_pair_hints(&self, baseline_win_rate: f64) -> Vec<(String, String)> {
+        let mut hints = Vec::new();
+
+        for pair in self
+            .get_pairs()
+            .iter()
+            .filter(|p| is_dangerous(p, baseline_win_rate))
+        {
+            let note = format!(
+                "banning it breaks up {} {} + {} ({}W-{}L against the pair)",
+                pair_label(pair.kind),
+                pair.first,
+                pair.second,
+                pair.wins,
+                pair.games - pair.wins
+            );
+            hints.push((pair.first.clone(), note.clone()));
+            hints.push((pair.second.clone(), note));
+        }
+
+        hints
+    }
+}
+
+/// Losing to the pair clearly more than usual
+pub fn is_dangerous(pair: &PairStats, baseline_win_rate: f64) -> bool {
+    pair.games >= PAIR_MIN_GAMES && pair.significance(baseline_win_rate) <= DANGER_SIGNIFICANCE
+}
+
+pub fn pair_label(kind: PairKind) -> &'static str {
+    match kind {
+        PairKind::EnemyBotLane => "bot lane",
+        PairKind::EnemyJungleMid => "jungle/mid",
+        PairKind::WithYou | PairKind::Allies => "pair",
+    }
+}
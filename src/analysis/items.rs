@@ -0,0 +1,244 @@
+use super::damage::{DamageProfile, Record};
+use super::matchups::find_lane_opponent;
+use crate::api::models::{DataDragonItems, EventDto, ParticipantDto, TimelineDto};
+use std::collections::HashMap;
+
+/// Cheapest item counted as a completed (legendary) item
+const COMPLETED_ITEM_GOLD: i32 = 2000;
+
+/// Completed items that make up a core build
+pub const CORE_ITEMS: usize = 3;
+
+/// Boots label when the player finished without any
+pub const NO_BOOTS: &str = "No boots";
+
+#[derive(Debug, Clone)]
+struct CatalogItem {
+    name: String,
+    completed: bool,
+    boots: bool,
+}
+
+/// Item names and kinds from Data Dragon
+pub struct ItemCatalog {
+    items: HashMap<i32, CatalogItem>,
+}
+
+impl ItemCatalog {
+    pub fn from_data(data: &DataDragonItems) -> Self {
+        let items = data
+            .data
+            .iter()
+            .filter_map(|(id, info)| {
+                let id: i32 = id.parse().ok()?;
+                let has_tag = |tag: &str| info.tags.iter().any(|t| t == tag);
+                // Tier 1 boots are a component, not a boots choice
+                let boots = has_tag("Boots") && info.gold.total > 300;
+                let completed = !boots
+                    && info.into.is_empty()
+                    && info.gold.total >= COMPLETED_ITEM_GOLD
+                    && !has_tag("Consumable")
+                    && !has_tag("Trinket");
+                Some((
+                    id,
+                    CatalogItem {
+                        name: info.name.clone(),
+                        completed,
+                        boots,
+                    },
+                ))
+            })
+            .collect();
+        ItemCatalog { items }
+    }
+
+    pub fn name(&self, id: i32) -> String {
+        self.items
+            .get(&id)
+            .map(|i| i.name.clone())
+            .unwrap_or_else(|| format!("Item {}", id))
+    }
+
+    pub fn is_completed(&self, id: i32) -> bool {
+        self.items.get(&id).is_some_and(|i| i.completed)
+    }
+
+    pub fn is_boots(&self, id: i32) -> bool {
+        self.items.get(&id).is_some_and(|i| i.boots)
+    }
+}
+
+/// Completed items in purchase order from the timeline, minus undone purchases
+fn purchase_order(timeline: &TimelineDto, participant_id: i32, catalog: &ItemCatalog) -> Vec<i32> {
+    let mut order: Vec<i32> = Vec::new();
+    for event in timeline.events() {
+        match event {
+            EventDto::ItemPurchased { participant_id: buyer, item_id, .. }
+                if *buyer == participant_id && catalog.is_completed(*item_id) && !order.contains(item_id) =>
+            {
+                order.push(*item_id);
+            }
+            // An undone purchase never happened
+            EventDto::ItemUndo { participant_id: buyer, before_id, .. } if *buyer == participant_id => {
+                order.retain(|id| id != before_id);
+            }
+            _ => {}
+        }
+    }
+    order
+}
+
+/// The player's itemization on one champion
+#[derive(Debug, Clone)]
+pub struct ChampionItems {
+    pub champion: String,
+    pub games: usize,
+    pub builds: HashMap<String, Record>,      // core build, e.g. "A > B > C"
+    pub first_items: HashMap<String, Record>, // first completed item
+    pub boots: HashMap<String, Record>,
+    pub by_enemy_profile: HashMap<(String, DamageProfile), Record>, // (first item or boots, enemy mix)
+    pub by_lane_opponent: HashMap<(String, String), Record>,        // (first item, opponent champion)
+}
+
+impl ChampionItems {
+    pub fn new(champion: String) -> Self {
+        ChampionItems {
+            champion,
+            games: 0,
+            builds: HashMap::new(),
+            first_items: HashMap::new(),
+            boots: HashMap::new(),
+            by_enemy_profile: HashMap::new(),
+            by_lane_opponent: HashMap::new(),
+        }
+    }
+
+    pub fn top_builds(&self, count: usize) -> Vec<(String, Record)> {
+        most_played(&self.builds, count)
+    }
+
+    pub fn top_first_items(&self, count: usize) -> Vec<(String, Record)> {
+        most_played(&self.first_items, count)
+    }
+
+    pub fn top_boots(&self, count: usize) -> Vec<(String, Record)> {
+        most_played(&self.boots, count)
+    }
+
+    pub fn against_profile(&self, choice: &str, profile: DamageProfile) -> Record {
+        self.by_enemy_profile
+            .get(&(choice.to_string(), profile))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// (first item, lane opponent, record) seen at least `min_games` times,
+    /// most games first
+    pub fn against_opponents(&self, min_games: usize) -> Vec<(String, String, Record)> {
+        let mut entries: Vec<(String, String, Record)> = self
+            .by_lane_opponent
+            .iter()
+            .filter(|(_, record)| record.games >= min_games)
+            .map(|((item, opponent), record)| (item.clone(), opponent.clone(), *record))
+            .collect();
+        entries.sort_by(|a, b| {
+            b.2.games
+                .cmp(&a.2.games)
+                .then_with(|| a.1.cmp(&b.1))
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        entries
+    }
+}
+
+fn most_played(map: &HashMap<String, Record>, count: usize) -> Vec<(String, Record)> {
+    let mut entries: Vec<(String, Record)> = map.iter().map(|(k, v)| (k.clone(), *v)).collect();
+    entries.sort_by(|a, b| b.1.games.cmp(&a.1.games).then_with(|| a.0.cmp(&b.0)));
+    entries.truncate(count);
+    entries
+}
+
+/// Tracks the player's builds per champion
+pub struct ItemTracker {
+    champions: HashMap<String, ChampionItems>,
+}
+
+impl ItemTracker {
+    pub fn new() -> Self {
+        ItemTracker {
+            champions: HashMap::new(),
+        }
+    }
+
+    /// Build order comes from the timeline when given, else from the final
+    /// inventory's slot order
+    pub fn add_game(
+        &mut self,
+        player: &ParticipantDto,
+        participants: &[ParticipantDto],
+        catalog: &ItemCatalog,
+        timeline: Option<&TimelineDto>,
+    ) {
+        let final_items = player.items();
+        let completed: Vec<i32> = match timeline.and_then(|t| t.participant_id(&player.puuid).map(|id| (t, id))) {
+            Some((timeline, id)) => purchase_order(timeline, id, catalog),
+            None => final_items.iter().copied().filter(|id| catalog.is_completed(*id)).collect(),
+        };
+        let boots = final_items
+            .iter()
+            .find(|id| catalog.is_boots(**id))
+            .map(|id| catalog.name(*id))
+            .unwrap_or_else(|| NO_BOOTS.to_string());
+        let first_item = completed.first().map(|id| catalog.name(*id));
+        let build = completed
+            .iter()
+            .take(CORE_ITEMS)
+            .map(|id| catalog.name(*id))
+            .collect::<Vec<_>>()
+            .join(" > ");
+
+        let enemies: Vec<&ParticipantDto> = participants
+            .iter()
+            .filter(|p| p.team_id != player.team_id)
+            .collect();
+        let enemy_profile = DamageProfile::classify(&enemies);
+        let opponent = find_lane_opponent(player, participants).map(|p| p.champion_name.clone());
+
+        let won = player.win;
+        let entry = self
+            .champions
+            .entry(player.champion_name.clone())
+            .or_insert_with(|| ChampionItems::new(player.champion_name.clone()));
+        entry.games += 1;
+        if !build.is_empty() {
+            entry.builds.entry(build).or_default().add(won);
+        }
+        entry.boots.entry(boots.clone()).or_default().add(won);
+
+        if let Some(profile) = enemy_profile {
+            entry.by_enemy_profile.entry((boots, profile)).or_default().add(won);
+            if let Some(ref item) = first_item {
+                entry.by_enemy_profile.entry((item.clone(), profile)).or_default().add(won);
+            }
+        }
+
+        if let Some(item) = first_item {
+            if let Some(opponent) = opponent {
+                entry.by_lane_opponent.entry((item.clone(), opponent)).or_default().add(won);
+            }
+            entry.first_items.entry(item).or_default().add(won);
+        }
+    }
+
+    /// Champions with at least `min_games` games, most played first
+    pub fn get_champions(&self, min_games: usize) -> Vec<ChampionItems> {
+        let mut champions: Vec<ChampionItems> = self
+            .champions
+            .values()
+            .filter(|c| c.games >= min_games)
+            .cloned()
+            .collect();
+        champions.sort_by(|a, b| b.games.cmp(&a.games).then_with(|| a.champion.cmp(&b.champion)));
+        champions
+    }
+}
@@ -0,0 +1,92 @@
+use crate::api::models::TimelineDto;
+
+/// Minutes at which lane diffs are sampled
+pub const CHECKPOINTS: [i64; 3] = [10, 15, 20];
+
+/// Checkpoint used to decide whether a loss was decided in lane
+const LANE_CHECKPOINT: usize = 1;
+
+/// Player minus lane opponent at one checkpoint
+#[derive(Debug, Clone, Copy)]
+pub struct LaneDiff {
+    pub gold: i32,
+    pub xp: i32,
+    pub cs: i32,
+}
+
+/// Diffs at each of `CHECKPOINTS`, `None` past the end of the game.
+/// Returns `None` if either player is missing from the timeline.
+pub fn lane_diffs(
+    timeline: &TimelineDto,
+    player_puuid: &str,
+    opponent_puuid: &str,
+) -> Option<[Option<LaneDiff>; 3]> {
+    let player_id = timeline.participant_id(player_puuid)?.to_string();
+    let opponent_id = timeline.participant_id(opponent_puuid)?.to_string();
+
+    Some(CHECKPOINTS.map(|minute| {
+        let frame = timeline.frame_at(minute)?;
+        let player = frame.participant_frames.get(&player_id)?;
+        let opponent = frame.participant_frames.get(&opponent_id)?;
+        Some(LaneDiff {
+            gold: player.total_gold - opponent.total_gold,
+            xp: player.xp - opponent.xp,
+            cs: player.creep_score() - opponent.creep_score(),
+        })
+    }))
+}
+
+/// Running lane diff averages for one champion or matchup
+#[derive(Debug, Clone, Default)]
+pub struct LaneDiffs {
+    pub games: usize,
+    totals: [(i64, i64, i64); 3],
+    samples: [usize; 3],
+    pub losses_behind: usize, // lost and behind in gold at 15
+    pub losses_ahead: usize,  // lost despite being even or ahead at 15
+}
+
+impl LaneDiffs {
+    pub fn add(&mut self, diffs: &[Option<LaneDiff>; 3], won: bool) {
+        self.games += 1;
+        for (i, diff) in diffs.iter().enumerate() {
+            if let Some(d) = diff {
+                self.totals[i].0 += d.gold as i64;
+                self.totals[i].1 += d.xp as i64;
+                self.totals[i].2 += d.cs as i64;
+                self.samples[i] += 1;
+            }
+        }
+
+        if !won {
+            match diffs[LANE_CHECKPOINT] {
+                Some(d) if d.gold < 0 => self.losses_behind += 1,
+                Some(_) => self.losses_ahead += 1,
+                None => {}
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: &LaneDiffs) {
+        self.games += other.games;
+        for i in 0..CHECKPOINTS.len() {
+            self.totals[i].0 += other.totals[i].0;
+            self.totals[i].1 += other.totals[i].1;
+            self.totals[i].2 += other.totals[i].2;
+            self.samples[i] += other.samples[i];
+        }
+        self.losses_behind += other.losses_behind;
+        self.losses_ahead += other.losses_ahead;
+    }
+
+    /// Average (gold, xp, cs) diff at checkpoint `index` of `CHECKPOINTS`
+    pub fn average(&self, index: usize) -> Option<(f64, f64, f64)> {
+        let samples = self.samples[index];
+        if samples == 0 {
+            return None;
+        }
+        let (gold, xp, cs) = self.totals[index];
+        let n = samples as f64;
+        Some((gold as f64 / n, xp as f64 / n, cs as f64 / n))
+    }
+}
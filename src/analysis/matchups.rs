@@ -0,0 +1,173 @@
+use super::lane_diffs::{LaneDiff, LaneDiffs};
+use super::protection::normalize_champion;
+use crate::api::models::ParticipantDto;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct MatchupStats {
+    pub player_champion: String,
+    pub opponent_champion: String,
+    pub role: String,
+    pub games: usize,
+    pub wins: usize,
+    pub gold_diff_total: i64,
+    pub cs_diff_total: i64,
+    pub xp_diff_total: i64,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub lane: LaneDiffs, // only filled from timelines
+}
+
+impl MatchupStats {
+    pub fn new(player_champion: String, opponent_champion: String, role: String) -> Self {
+        MatchupStats {
+            player_champion,
+            opponent_champion,
+            role,
+            games: 0,
+            wins: 0,
+            gold_diff_total: 0,
+            cs_diff_total: 0,
+            xp_diff_total: 0,
+            kills: 0,
+            deaths: 0,
+            assists: 0,
+            lane: LaneDiffs::default(),
+        }
+    }
+
+    pub fn losses(&self) -> usize {
+        self.games - self.wins
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+
+    fn average(&self, total: i64) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            total as f64 / self.games as f64
+        }
+    }
+
+    /// Average end-of-game gold difference (positive = ahead of the opponent)
+    pub fn avg_gold_diff(&self) -> f64 {
+        self.average(self.gold_diff_total)
+    }
+
+    pub fn avg_cs_diff(&self) -> f64 {
+        self.average(self.cs_diff_total)
+    }
+
+    pub fn avg_xp_diff(&self) -> f64 {
+        self.average(self.xp_diff_total)
+    }
+
+    /// (kills + assists) / deaths, with deaths floored at 1
+    pub fn kda(&self) -> f64 {
+        (self.kills + self.assists) as f64 / self.deaths.max(1) as f64
+    }
+}
+
+/// Tracks the player against the enemy in the same `teamPosition`
+pub struct MatchupTracker {
+    matchups: HashMap<(String, String), MatchupStats>,
+}
+
+impl MatchupTracker {
+    pub fn new() -> Self {
+        MatchupTracker {
+            matchups: HashMap::new(),
+        }
+    }
+
+    pub fn add_matchup(&mut self, player: &ParticipantDto, opponent: &ParticipantDto) {
+        let key = (player.champion_name.clone(), opponent.champion_name.clone());
+        let entry = self.matchups.entry(key).or_insert_with(|| {
+            MatchupStats::new(
+                player.champion_name.clone(),
+                opponent.champion_name.clone(),
+                player.team_position.clone(),
+            )
+        });
+
+        entry.games += 1;
+        if player.win {
+            entry.wins += 1;
+        }
+        entry.gold_diff_total += (player.gold_earned - opponent.gold_earned) as i64;
+        entry.cs_diff_total += (player.creep_score() - opponent.creep_score()) as i64;
+        entry.xp_diff_total += (player.champ_experience - opponent.champ_experience) as i64;
+        entry.kills += player.kills as i64;
+        entry.deaths += player.deaths as i64;
+        entry.assists += player.assists as i64;
+    }
+
+    /// Timeline lane diffs for a matchup already added with `add_matchup`
+    pub fn add_lane_diffs(&mut self, player: &ParticipantDto, opponent: &ParticipantDto, diffs: &[Option<LaneDiff>; 3]) {
+        let key = (player.champion_name.clone(), opponent.champion_name.clone());
+        if let Some(entry) = self.matchups.get_mut(&key) {
+            entry.lane.add(diffs, player.win);
+        }
+    }
+
+    /// Lane diffs per enemy lane opponent across all your champions,
+    /// most games first
+    pub fn lane_by_opponent(&self) -> Vec<(String, LaneDiffs)> {
+        let mut by_opponent: HashMap<String, LaneDiffs> = HashMap::new();
+        for matchup in self.matchups.values().filter(|m| m.lane.games > 0) {
+            by_opponent
+                .entry(matchup.opponent_champion.clone())
+                .or_default()
+                .merge(&matchup.lane);
+        }
+
+        let mut result: Vec<(String, LaneDiffs)> = by_opponent.into_iter().collect();
+        result.sort_by(|a, b| b.1.games.cmp(&a.1.games).then_with(|| a.0.cmp(&b.0)));
+        result
+    }
+
+    /// Player on `player_champion` against `opponent_champion`, ignoring
+    /// case, spaces and punctuation
+    pub fn find_matchup(&self, player_champion: &str, opponent_champion: &str) -> Option<&MatchupStats> {
+        let player = normalize_champion(player_champion);
+        let opponent = normalize_champion(opponent_champion);
+        self.matchups.values().find(|m| {
+            normalize_champion(&m.player_champion) == player
+                && normalize_champion(&m.opponent_champion) == opponent
+        })
+    }
+
+    /// All matchups, most losses first (ties broken by lower win rate)
+    pub fn get_matchups(&self) -> Vec<MatchupStats> {
+        let mut matchups: Vec<MatchupStats> = self.matchups.values().cloned().collect();
+        matchups.sort_by(|a, b| {
+            b.losses()
+                .cmp(&a.losses())
+                .then_with(|| a.win_rate().partial_cmp(&b.win_rate()).unwrap_or(std::cmp::Ordering::Equal))
+                .then_with(|| b.games.cmp(&a.games))
+        });
+        matchups
+    }
+}
+
+/// The enemy who played the same `teamPosition` as the player, if any
+pub fn find_lane_opponent<'a>(
+    player: &ParticipantDto,
+    participants: &'a [ParticipantDto],
+) -> Option<&'a ParticipantDto> {
+    if player.team_position.is_empty() {
+        return None;
+    }
+
+    participants
+        .iter()
+        .find(|p| p.team_id != player.team_id && p.team_position == player.team_position)
+}
@@ -0,0 +1,116 @@
+use crate::api::models::ParticipantDto;
+use std::collections::HashMap;
+
+/// The player's per-game performance numbers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GameMetrics {
+    pub kill_participation: f64, // (kills + assists) / team kills, 0-1
+    pub damage_share: f64,       // share of team damage to champions, 0-1
+    pub gold_share: f64,         // share of team gold, 0-1
+    pub vision_per_min: f64,
+    pub cs_per_min: f64,
+    pub deaths: f64,
+}
+
+fn share(part: f64, total: f64) -> f64 {
+    if total > 0.0 {
+        part / total
+    } else {
+        0.0
+    }
+}
+
+impl GameMetrics {
+    pub fn compute(player: &ParticipantDto, participants: &[ParticipantDto], game_minutes: f64) -> Self {
+        let team: Vec<&ParticipantDto> = participants
+            .iter()
+            .filter(|p| p.team_id == player.team_id)
+            .collect();
+        let team_kills: i32 = team.iter().map(|p| p.kills).sum();
+        let team_damage: i64 = team.iter().map(|p| p.total_damage_dealt_to_champions).sum();
+        let team_gold: i32 = team.iter().map(|p| p.gold_earned).sum();
+
+        GameMetrics {
+            kill_participation: share((player.kills + player.assists) as f64, team_kills as f64),
+            damage_share: share(player.total_damage_dealt_to_champions as f64, team_damage as f64),
+            gold_share: share(player.gold_earned as f64, team_gold as f64),
+            vision_per_min: share(player.vision_score as f64, game_minutes),
+            cs_per_min: share(player.creep_score() as f64, game_minutes),
+            deaths: player.deaths as f64,
+        }
+    }
+}
+
+/// Running averages of `GameMetrics`
+#[derive(Debug, Clone, Default)]
+pub struct MetricAverages {
+    pub games: usize,
+    totals: GameMetrics,
+}
+
+impl MetricAverages {
+    pub fn add(&mut self, metrics: &GameMetrics) {
+        self.games += 1;
+        self.totals.kill_participation += metrics.kill_participation;
+        self.totals.damage_share += metrics.damage_share;
+        self.totals.gold_share += metrics.gold_share;
+        self.totals.vision_per_min += metrics.vision_per_min;
+        self.totals.cs_per_min += metrics.cs_per_min;
+        self.totals.deaths += metrics.deaths;
+    }
+
+    pub fn average(&self) -> GameMetrics {
+        let n = self.games.max(1) as f64;
+        GameMetrics {
+            kill_participation: self.totals.kill_participation / n,
+            damage_share: self.totals.damage_share / n,
+            gold_share: self.totals.gold_share / n,
+            vision_per_min: self.totals.vision_per_min / n,
+            cs_per_min: self.totals.cs_per_min / n,
+            deaths: self.totals.deaths / n,
+        }
+    }
+}
+
+/// Averages the player's metrics per role and per champion
+pub struct MetricsTracker {
+    by_role: HashMap<String, MetricAverages>,
+    by_champion: HashMap<String, MetricAverages>,
+}
+
+impl MetricsTracker {
+    pub fn new() -> Self {
+        MetricsTracker {
+            by_role: HashMap::new(),
+            by_champion: HashMap::new(),
+        }
+    }
+
+    pub fn add_game(&mut self, player: &ParticipantDto, metrics: &GameMetrics) {
+        self.by_role
+            .entry(player.team_position.clone())
+            .or_default()
+            .add(metrics);
+        self.by_champion
+            .entry(player.champion_name.clone())
+            .or_default()
+            .add(metrics);
+    }
+
+    /// `teamPosition` -> averages, most games first
+    pub fn by_role(&self) -> Vec<(String, MetricAverages)> {
+        sorted(&self.by_role)
+    }
+
+    /// Champion -> averages, most games first
+    pub fn by_champion(&self) -> Vec<(String, MetricAverages)> {
+        sorted(&self.by_champion)
+    }
+}
+
+fn sorted(map: &HashMap<String, MetricAverages>) -> Vec<(String, MetricAverages)> {
+    let mut entries: Vec<(String, MetricAverages)> =
+        map.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    entries.sort_by(|a, b| b.1.games.cmp(&a.1.games).then_with(|| a.0.cmp(&b.0)));
+    entries
+}
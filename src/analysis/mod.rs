@@ -1,2 +1,23 @@
+pub mod archetypes;
+pub mod backtest;
+pub mod champion_pool;
 pub mod champion_stats;
+pub mod counters;
+pub mod damage;
+pub mod deaths;
+pub mod enemy_pairs;
+pub mod items;
+pub mod lane_diffs;
+pub mod matchups;
+pub mod metrics;
+pub mod objectives;
+pub mod opponents;
+pub mod picks;
+pub mod protection;
+pub mod recency;
 pub mod recommender;
+pub mod roles;
+pub mod scoring;
+pub mod synergy;
+pub mod teammates;
+pub mod win_probability;
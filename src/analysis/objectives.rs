@@ -0,0 +1,170 @@
+use super::champion_stats::significance;
+use crate::api::models::{EventDto, TeamDto, TimelineDto};
+
+/// Dragons that grant the soul
+const SOUL_DRAGONS: i32 = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Objective {
+    FirstBlood,
+    FirstTower,
+    FirstDragon,
+    Grubs,
+    Herald,
+    DragonSoul,
+    Elder,
+    Baron,
+}
+
+impl Objective {
+    pub const ALL: [Objective; 8] = [
+        Objective::FirstBlood,
+        Objective::FirstTower,
+        Objective::FirstDragon,
+        Objective::Grubs,
+        Objective::Herald,
+        Objective::DragonSoul,
+        Objective::Elder,
+        Objective::Baron,
+    ];
+
+    pub fn label(&self) -> &str {
+        match self {
+            Objective::FirstBlood => "First blood",
+            Objective::FirstTower => "First tower",
+            Objective::FirstDragon => "First dragon",
+            Objective::Grubs => "Grubs (more than enemy)",
+            Objective::Herald => "Rift Herald",
+            Objective::DragonSoul => "Dragon soul",
+            Objective::Elder => "Elder dragon",
+            Objective::Baron => "Baron (any)",
+        }
+    }
+
+    /// Whether `ours` secured this objective against `theirs`; `None` when
+    /// neither team took it or the match data can't tell
+    fn secured(
+        &self,
+        ours: &TeamDto,
+        theirs: &TeamDto,
+        timeline: Option<&TimelineDto>,
+    ) -> Option<bool> {
+        let (o, t) = (&ours.objectives, &theirs.objectives);
+        match self {
+            Objective::FirstBlood => (o.champion.kills + t.champion.kills > 0).then_some(o.champion.first),
+            Objective::FirstTower => (o.tower.kills + t.tower.kills > 0).then_some(o.tower.first),
+            Objective::FirstDragon => (o.dragon.kills + t.dragon.kills > 0).then_some(o.dragon.first),
+            Objective::Grubs => (o.horde.kills != t.horde.kills).then_some(o.horde.kills > t.horde.kills),
+            Objective::Herald => (o.rift_herald.kills + t.rift_herald.kills > 0).then_some(o.rift_herald.kills > 0),
+            Objective::DragonSoul => match timeline {
+                Some(timeline) => timeline.events().find_map(|e| match e {
+                    EventDto::DragonSoulGiven { team_id, .. } => Some(*team_id == ours.team_id),
+                    _ => None,
+                }),
+                None if o.dragon.kills >= SOUL_DRAGONS => Some(true),
+                None if t.dragon.kills >= SOUL_DRAGONS => Some(false),
+                None => None,
+            },
+            // Elder can't be told apart from other dragons without a timeline
+            Objective::Elder => {
+                let mut elders = timeline?.events().filter_map(|e| match e {
+                    EventDto::EliteMonsterKill { killer_team_id, monster_sub_type, .. }
+                        if monster_sub_type == "ELDER_DRAGON" =>
+                    {
+                        Some(*killer_team_id == ours.team_id)
+                    }
+                    _ => None,
+                });
+                elders.next()
+            }
+            Objective::Baron => (o.baron.kills + t.baron.kills > 0).then_some(o.baron.kills > 0),
+        }
+    }
+}
+
+/// Results for one objective over the games where it was contested
+#[derive(Debug, Clone, Default)]
+pub struct ObjectiveStats {
+    pub secured: usize,
+    pub secured_wins: usize,
+    pub conceded: usize,
+    pub conceded_wins: usize,
+}
+
+impl ObjectiveStats {
+    pub fn games(&self) -> usize {
+        self.secured + self.conceded
+    }
+
+    /// Share (0-1) of games in which the player's team took it
+    pub fn control_rate(&self) -> f64 {
+        if self.games() == 0 {
+            0.0
+        } else {
+            self.secured as f64 / self.games() as f64
+        }
+    }
+
+    pub fn win_rate_secured(&self) -> Option<f64> {
+        (self.secured > 0).then(|| self.secured_wins as f64 / self.secured as f64)
+    }
+
+    pub fn win_rate_conceded(&self) -> Option<f64> {
+        (self.conceded > 0).then(|| self.conceded_wins as f64 / self.conceded as f64)
+    }
+
+    /// z-score of the win rate when secured against the player's baseline
+    pub fn significance(&self, baseline_win_rate: f64) -> f64 {
+        significance(self.secured_wins, self.secured, baseline_win_rate)
+    }
+}
+
+/// Tracks which team took each objective and how those games ended
+pub struct ObjectiveTracker {
+    stats: Vec<ObjectiveStats>, // indexed like `Objective::ALL`
+}
+
+impl ObjectiveTracker {
+    pub fn new() -> Self {
+        ObjectiveTracker {
+            stats: vec![ObjectiveStats::default(); Objective::ALL.len()],
+        }
+    }
+
+    pub fn add_game(&mut self, our_team_id: i32, won: bool, teams: &[TeamDto], timeline: Option<&TimelineDto>) {
+        let Some(ours) = teams.iter().find(|t| t.team_id == our_team_id) else {
+            return;
+        };
+        let Some(theirs) = teams.iter().find(|t| t.team_id != our_team_id) else {
+            return;
+        };
+
+        for (objective, stats) in Objective::ALL.iter().zip(self.stats.iter_mut()) {
+            match objective.secured(ours, theirs, timeline) {
+                Some(true) => {
+                    stats.secured += 1;
+                    if won {
+                        stats.secured_wins += 1;
+                    }
+                }
+                Some(false) => {
+                    stats.conceded += 1;
+                    if won {
+                        stats.conceded_wins += 1;
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// Objectives seen in at least one game
+    pub fn get_objectives(&self) -> Vec<(Objective, ObjectiveStats)> {
+        Objective::ALL
+            .iter()
+            .zip(self.stats.iter())
+            .filter(|(_, stats)| stats.games() > 0)
+            .map(|(objective, stats)| (*objective, stats.clone()))
+            .collect()
+    }
+}
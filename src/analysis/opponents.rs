@@ -0,0 +1,140 @@
+use crate::api::models::ParticipantDto;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Meetings before an enemy player counts as a repeat opponent
+pub const REPEAT_MIN_GAMES: usize = 2;
+
+/// An enemy player (by PUUID) the player has met
+#[derive(Debug, Clone)]
+pub struct OpponentStats {
+    #[allow(dead_code)]
+    pub puuid: String,
+    pub riot_id: String,
+    pub games: usize,
+    pub wins: usize, // the player's wins against them
+    pub champions: HashMap<String, usize>,
+    pub last_match_id: String,
+    pub last_seen: Option<DateTime<Utc>>,
+}
+
+impl OpponentStats {
+    pub fn new(puuid: String, riot_id: String) -> Self {
+        OpponentStats {
+            puuid,
+            riot_id,
+            games: 0,
+            wins: 0,
+            champions: HashMap::new(),
+            last_match_id: String::new(),
+            last_seen: None,
+        }
+    }
+
+    pub fn losses(&self) -> usize {
+        self.games - self.wins
+    }
+
+    /// Champions they played against us, most played first
+    pub fn champions(&self) -> Vec<(String, usize)> {
+        let mut champions: Vec<(String, usize)> = self
+            .champions
+            .iter()
+            .map(|(name, count)| (name.clone(), *count))
+            .collect();
+        champions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        champions
+    }
+
+    pub fn main_champion(&self) -> Option<String> {
+        self.champions().into_iter().next().map(|(name, _)| name)
+    }
+
+    /// Met more than once and beat us more often than not
+    pub fn is_nemesis(&self) -> bool {
+        self.games >= REPEAT_MIN_GAMES && self.losses() > self.wins
+    }
+}
+
+/// Tracks enemy players by PUUID across games
+pub struct OpponentTracker {
+    opponents: HashMap<String, OpponentStats>,
+}
+
+impl OpponentTracker {
+    pub fn new() -> Self {
+        OpponentTracker {
+            opponents: HashMap::new(),
+        }
+    }
+
+    pub fn add_game(
+        &mut self,
+        player: &ParticipantDto,
+        participants: &[ParticipantDto],
+        match_id: &str,
+        ended_at: Option<DateTime<Utc>>,
+    ) {
+        for p in participants
+            .iter()
+            .filter(|p| p.team_id != player.team_id && !p.puuid.is_empty())
+        {
+            let entry = self
+                .opponents
+                .entry(p.puuid.clone())
+                .or_insert_with(|| OpponentStats::new(p.puuid.clone(), p.riot_id()));
+
+            entry.games += 1;
+            if player.win {
+                entry.wins += 1;
+            }
+            *entry.champions.entry(p.champion_name.clone()).or_insert(0) += 1;
+
+            let newer = match (ended_at, entry.last_seen) {
+                (Some(ended_at), Some(last_seen)) => ended_at > last_seen,
+                (Some(_), None) => true,
+                (None, _) => entry.last_match_id.is_empty(),
+            };
+            if newer {
+                entry.last_match_id = match_id.to_string();
+                entry.last_seen = ended_at;
+            }
+        }
+    }
+
+    /// Opponents met at least `REPEAT_MIN_GAMES` times, nemeses and most
+    /// losses first
+    pub fn repeat_opponents(&self) -> Vec<OpponentStats> {
+        let mut opponents: Vec<OpponentStats> = self
+            .opponents
+            .values()
+            .filter(|o| o.games >= REPEAT_MIN_GAMES)
+            .cloned()
+            .collect();
+        opponents.sort_by(|a, b| {
+            b.is_nemesis()
+                .cmp(&a.is_nemesis())
+                .then_with(|| b.losses().cmp(&a.losses()))
+                .then_with(|| b.games.cmp(&a.games))
+        });
+        opponents
+    }
+
+    /// "Ban their main" hints: nemesis main champion -> note
+    pub fn nemesis_ban_hints(&self) -> Vec<(String, String)> {
+        self.repeat_opponents()
+            .into_iter()
+            .filter(|o| o.is_nemesis())
+            .filter_map(|o| {
+                let main = o.main_champion()?;
+                let note = format!(
+                    "main of your nemesis {} ({}W-{}L against them)",
+                    o.riot_id,
+                    o.wins,
+                    o.losses()
+                );
+                Some((main, note))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,72 @@
+use super::champion_stats::ChampionStatsTracker;
+use super::protection::normalize_champion;
+use super::recommender::BanRecommendation;
+use std::collections::HashMap;
+
+/// Below this many games on a pick, its bans fall back to the whole history
+pub const MIN_PICK_GAMES: usize = 5;
+
+/// Enemy stats restricted to the games where the player was on a given champion
+pub struct PickHistory {
+    pub champion: String,
+    pub tracker: ChampionStatsTracker,
+    pub games: Vec<(bool, f64)>, // (won, recency weight)
+}
+
+/// Ban recommendations for one intended pick
+#[derive(Debug, Clone)]
+pub struct PickBans {
+    pub champion: String,
+    pub games_on_pick: usize,
+    /// Too few games on the pick, so the whole history was used
+    pub fallback: bool,
+    pub recommendations: Vec<BanRecommendation>,
+}
+
+pub struct PickTracker {
+    picks: HashMap<String, PickHistory>,
+}
+
+impl PickTracker {
+    pub fn new() -> Self {
+        PickTracker {
+            picks: HashMap::new(),
+        }
+    }
+
+    /// Record a game on `champion`; feed its enemies into the returned tracker
+    pub fn add_game(&mut self, champion: &str, won: bool, recency_weight: f64) -> &mut ChampionStatsTracker {
+        let entry = self
+            .picks
+            .entry(normalize_champion(champion))
+            .or_insert_with(|| PickHistory {
+                champion: champion.to_string(),
+                tracker: ChampionStatsTracker::new(),
+                games: Vec::new(),
+            });
+
+        entry.games.push((won, recency_weight));
+        &mut entry.tracker
+    }
+
+    pub fn get(&self, champion: &str) -> Option<&PickHistory> {
+        self.picks.get(&normalize_champion(champion))
+    }
+
+    pub fn all_picks(&self) -> Vec<&PickHistory> {
+        self.picks.values().collect()
+    }
+
+    /// The `count` champions the player played most
+    pub fn top_picks(&self, count: usize) -> Vec<&PickHistory> {
+        let mut picks: Vec<&PickHistory> = self.picks.values().collect();
+        picks.sort_by(|a, b| {
+            b.games
+                .len()
+                .cmp(&a.games.len())
+                .then_with(|| a.champion.cmp(&b.champion))
+        });
+        picks.truncate(count);
+        picks
+    }
+}
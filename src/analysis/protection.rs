@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+/// Lowercase and strip everything but letters and digits, so "Lee Sin",
+/// "LeeSin" and "leesin" all match
+pub fn normalize_champion(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Champions that must never be recommended as bans, with the reason why
+#[derive(Debug, Clone)]
+pub struct ProtectList {
+    reasons: HashMap<String, String>,
+}
+
+impl ProtectList {
+    pub fn new() -> Self {
+        ProtectList {
+            reasons: HashMap::new(),
+        }
+    }
+
+    /// Keeps the first reason given for a champion
+    pub fn add(&mut self, champion: &str, reason: &str) {
+        let key = normalize_champion(champion);
+        if !key.is_empty() {
+            self.reasons.entry(key).or_insert_with(|| reason.to_string());
+        }
+    }
+
+    /// Add a comma-separated list (`--protect` / `PROTECTED_CHAMPIONS`)
+    pub fn add_list(&mut self, champions: &str, reason: &str) {
+        for champion in champions.split(',') {
+            self.add(champion.trim(), reason);
+        }
+    }
+
+    /// Protect the `count` champions the player played most
+    pub fn add_top_played(&mut self, played: &[String], count: usize) {
+        let mut games: HashMap<&str, usize> = HashMap::new();
+        for champion in played {
+            *games.entry(champion.as_str()).or_insert(0) += 1;
+        }
+
+        let mut ranked: Vec<(&str, usize)> = games.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+        for (champion, games) in ranked.into_iter().take(count) {
+            self.add(champion, &format!("your pool ({} games)", games));
+        }
+    }
+
+    pub fn reason(&self, champion: &str) -> Option<&str> {
+        self.reasons
+            .get(&normalize_champion(champion))
+            .map(|r| r.as_str())
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Parse a half-life such as `14d`, `36h`, `2w` (a bare number means days)
+pub fn parse_half_life(input: &str) -> Option<Duration> {
+    let input = input.trim().to_ascii_lowercase();
+    let (value, unit) = match input.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(idx) => input.split_at(idx),
+        None => (input.as_str(), "d"),
+    };
+    let value: f64 = value.trim().parse().ok()?;
+    if value <= 0.0 {
+        return None;
+    }
+
+    let hours = match unit {
+        "h" => value,
+        "d" => value * 24.0,
+        "w" => value * 24.0 * 7.0,
+        _ => return None,
+    };
+
+    let seconds = hours * 3600.0;
+    if !seconds.is_finite() || seconds >= i64::MAX as f64 {
+        return None;
+    }
+    Duration::try_seconds(seconds as i64)
+}
+
+/// Exponential decay weight of a game that ended at `ended_at`:
+/// 1.0 for a game played `now`, 0.5 one half-life ago, 0.25 two half-lives ago
+pub fn decay_weight(ended_at: DateTime<Utc>, now: DateTime<Utc>, half_life: Duration) -> f64 {
+    let age = now.signed_duration_since(ended_at).num_seconds().max(0) as f64;
+    let half_life = half_life.num_seconds().max(1) as f64;
+    0.5_f64.powf(age / half_life)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_half_life_rejects_out_of_range_values() {
+        assert_eq!(parse_half_life("99999999999999999d"), None);
+        assert_eq!(parse_half_life("14d"), Some(Duration::days(14)));
+    }
+}
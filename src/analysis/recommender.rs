@@ -1,4 +1,9 @@
 use super::champion_stats::ChampionStats;
+use super::protection::{normalize_champion, ProtectList};
+use super::scoring::{ScoreComponent, ScoringContext, ScoringStrategy};
+
+/// z-score for the 95% confidence interval
+pub const CONFIDENCE_Z: f64 = 1.96;
 
 #[derive(Debug, Clone)]
 pub struct BanRecommendation {
@@ -6,7 +11,14 @@ pub struct BanRecommendation {
     pub score: f64,
     pub frequency: f64,
     pub win_rate: f64,
+    pub adjusted_win_rate: f64, // shrunk toward the player's overall win rate
+    pub confidence_interval: (f64, f64),
     pub times_faced: usize,
+    pub roles: Vec<(String, usize)>, // teamPosition -> games, most common first
+    pub components: Vec<ScoreComponent>, // before the role factor
+    pub role_factor: f64,
+    pub matches: Vec<(String, bool)>, // (match ID, won) where this champion was faced
+    pub notes: Vec<String>, // extra reasons to ban, e.g. a nemesis's main
 }
 
 #[derive(Debug, Clone)]
@@ -17,20 +29,42 @@ pub struct AllyAnalysis {
     pub win_rate: f64,
 }
 
+/// Scale a strategy score by a role factor. Negative scores (e.g. a champion
+/// you beat more often than usual under `winrate-delta`) are divided instead,
+/// so a lower factor always ranks the champion lower.
+pub fn apply_role_factor(score: f64, role_factor: f64) -> f64 {
+    if score < 0.0 && role_factor > 0.0 {
+        score / role_factor
+    } else {
+        score * role_factor
+    }
+}
+
 impl BanRecommendation {
-    pub fn new(
-        champion_name: String,
-        score: f64,
-        frequency: f64,
-        win_rate: f64,
-        times_faced: usize,
+    /// Score with `strategy`, scaled by the champion's average role weight
+    /// (lane opponents and junglers count more than off-role enemies).
+    pub fn from_stats(
+        stats: &ChampionStats,
+        strategy: &dyn ScoringStrategy,
+        ctx: &ScoringContext,
     ) -> Self {
+        let components = strategy.components(stats, ctx);
+        let role_factor = stats.role_factor();
+        let score = apply_role_factor(strategy.score(stats, ctx), role_factor);
+
         BanRecommendation {
-            champion_name,
+            champion_name: stats.name.clone(),
             score,
-            frequency,
-            win_rate,
-            times_faced,
+            frequency: stats.frequency(ctx.total_games),
+            win_rate: stats.win_rate(),
+            adjusted_win_rate: ctx.adjusted_win_rate(stats),
+            confidence_interval: stats.wilson_interval(CONFIDENCE_Z),
+            times_faced: stats.times_faced,
+            roles: stats.roles(),
+            components,
+            role_factor,
+            matches: stats.matches.clone(),
+            notes: Vec::new(),
         }
     }
 }
@@ -59,50 +93,18 @@ impl AllyAnalysis {
 pub struct BanRecommender;
 
 impl BanRecommender {
-    /// Calculate ban score based on:
-    /// - 0.4 × frequency
-    /// - 0.5 × (1 - win_rate)
-    /// - 0.1 × recency (normalized to 0-1)
-    pub fn calculate_score(
-        stats: &ChampionStats,
-        total_games: usize,
-        max_recency: f64,
-    ) -> f64 {
-        let frequency = stats.frequency(total_games) / 100.0;
-        let win_rate = stats.win_rate();
-        let recency_normalized = if max_recency > 0.0 {
-            stats.recency_score / max_recency
-        } else {
-            0.0
-        };
-
-        (0.4 * frequency) + (0.5 * (1.0 - win_rate)) + (0.1 * recency_normalized)
-    }
-
+    /// Champions faced fewer than `min_games` times are not recommended.
     pub fn get_recommendations(
         stats: Vec<ChampionStats>,
-        total_games: usize,
+        strategy: &dyn ScoringStrategy,
+        ctx: &ScoringContext,
         top_n: usize,
+        min_games: usize,
     ) -> Vec<BanRecommendation> {
-        let max_recency = stats
-            .iter()
-            .map(|s| s.recency_score)
-            .fold(f64::NEG_INFINITY, f64::max);
-
         let mut recommendations: Vec<BanRecommendation> = stats
             .iter()
-            .map(|s| {
-                let score = Self::calculate_score(s, total_games, max_recency);
-                let frequency = s.frequency(total_games);
-                let win_rate = s.win_rate();
-                BanRecommendation::new(
-                    s.name.clone(),
-                    score,
-                    frequency,
-                    win_rate,
-                    s.times_faced,
-                )
-            })
+            .filter(|s| s.times_faced >= min_games)
+            .map(|s| BanRecommendation::from_stats(s, strategy, ctx))
             .collect();
 
         recommendations.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
@@ -111,6 +113,47 @@ impl BanRecommender {
         recommendations
     }
 
+    /// Attach (champion, note) hints to the matching recommendations
+    pub fn annotate(recommendations: &mut [BanRecommendation], hints: &[(String, String)]) {
+        for rec in recommendations.iter_mut() {
+            let key = normalize_champion(&rec.champion_name);
+            for (champion, note) in hints {
+                if normalize_champion(champion) == key {
+                    rec.notes.push(note.clone());
+                }
+            }
+        }
+    }
+
+    /// Hints whose champion is not among `recommendations` and not protected
+    pub fn unmatched_hints(
+        recommendations: &[BanRecommendation],
+        hints: &[(String, String)],
+        protected: &ProtectList,
+    ) -> Vec<(String, String)> {
+        hints
+            .iter()
+            .filter(|(champion, _)| {
+                let key = normalize_champion(champion);
+                protected.reason(champion).is_none()
+                    && !recommendations
+                        .iter()
+                        .any(|rec| normalize_champion(&rec.champion_name) == key)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Split stats into (bannable, protected)
+    pub fn partition_protected(
+        stats: Vec<ChampionStats>,
+        protected: &ProtectList,
+    ) -> (Vec<ChampionStats>, Vec<ChampionStats>) {
+        stats
+            .into_iter()
+            .partition(|s| protected.reason(&s.name).is_none())
+    }
+
     pub fn analyze_allies(
         ally_stats: Vec<ChampionStats>,
         min_games_together: usize,
@@ -133,3 +176,43 @@ impl BanRecommender {
         analyses
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::champion_stats::ChampionStatsTracker;
+    use crate::analysis::scoring::WinRateDelta;
+
+    #[test]
+    fn role_factor_keeps_lane_opponent_first_for_negative_scores() {
+        // Beaten 3 of 4 times each, against a 50% baseline: negative deltas
+        let mut tracker = ChampionStatsTracker::new();
+        for (idx, won) in [true, true, true, false].into_iter().enumerate() {
+            let match_id = format!("NA1_{}", idx);
+            tracker.add_champion_encounter("Zed".to_string(), won, 1.0, "MIDDLE", 1.0, &match_id);
+            tracker.add_champion_encounter("Garen".to_string(), won, 1.0, "TOP", 0.5, &match_id);
+        }
+        let games: Vec<(bool, f64)> = (0..10).map(|i| (i % 2 == 0, 1.0)).collect();
+
+        let stats = tracker.get_stats();
+        let ctx = ScoringContext::new(&stats, &games, false);
+        let recommendations = BanRecommender::get_recommendations(stats, &WinRateDelta, &ctx, 2, 1);
+
+        assert!(recommendations.iter().all(|rec| rec.score < 0.0));
+        assert_eq!(recommendations[0].champion_name, "Zed");
+    }
+
+    #[test]
+    fn unmatched_hints_skip_protected_champions() {
+        let mut protected = ProtectList::new();
+        protected.add("Lee Sin", "--protect");
+        let hints = vec![
+            ("LeeSin".to_string(), "main of your nemesis".to_string()),
+            ("Zed".to_string(), "main of your nemesis".to_string()),
+        ];
+
+        let unmatched = BanRecommender::unmatched_hints(&[], &hints, &protected);
+
+        assert_eq!(unmatched, vec![("Zed".to_string(), "main of your nemesis".to_string())]);
+    }
+}
@@ -0,0 +1,101 @@
+/// Parse a user-supplied role (`mid`, `adc`, `support`, ...) into a `teamPosition`
+pub fn parse_role(input: &str) -> Option<&'static str> {
+    match input.to_ascii_lowercase().as_str() {
+        "top" => Some("TOP"),
+        "jungle" | "jg" | "jungler" => Some("JUNGLE"),
+        "mid" | "middle" => Some("MIDDLE"),
+        "bot" | "bottom" | "adc" | "carry" => Some("BOTTOM"),
+        "support" | "supp" | "sup" | "utility" => Some("UTILITY"),
+        _ => None,
+    }
+}
+
+/// Short label for a `teamPosition`
+pub fn role_label(position: &str) -> &str {
+    match position {
+        "TOP" => "Top",
+        "JUNGLE" => "Jungle",
+        "MIDDLE" => "Mid",
+        "BOTTOM" => "Bot",
+        "UTILITY" => "Support",
+        "" => "?",
+        other => other,
+    }
+}
+
+/// Weights applied to an enemy depending on the role they played relative to ours
+#[derive(Debug, Clone, Copy)]
+pub struct RoleWeights {
+    pub lane_opponent: f64,
+    pub jungler: f64,
+    pub other: f64,
+}
+
+impl Default for RoleWeights {
+    fn default() -> Self {
+        RoleWeights {
+            lane_opponent: 1.0,
+            jungler: 0.8,
+            other: 0.5,
+        }
+    }
+}
+
+impl RoleWeights {
+    /// Parse `lane,jungle,other` (e.g. `1.0,0.8,0.5`). Weights must be
+    /// finite and non-negative.
+    pub fn parse(input: &str) -> Option<Self> {
+        let values: Vec<f64> = input
+            .split(',')
+            .map(|v| v.trim().parse::<f64>())
+            .collect::<Result<_, _>>()
+            .ok()?;
+        if !values.iter().all(|v| v.is_finite() && *v >= 0.0) {
+            return None;
+        }
+
+        match values.as_slice() {
+            [lane_opponent, jungler, other] => Some(RoleWeights {
+                lane_opponent: *lane_opponent,
+                jungler: *jungler,
+                other: *other,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Weight of an enemy in `enemy_role` when we play `player_role`.
+    /// Unknown roles (ARAM, remakes) are weighted neutrally.
+    pub fn weight(&self, player_role: &str, enemy_role: &str) -> f64 {
+        if player_role.is_empty() || enemy_role.is_empty() {
+            return 1.0;
+        }
+
+        if is_lane_opponent(player_role, enemy_role) {
+            self.lane_opponent
+        } else if enemy_role == "JUNGLE" {
+            self.jungler
+        } else {
+            self.other
+        }
+    }
+}
+
+/// Bot lane is a 2v2, so both enemy bot laners count as lane opponents
+pub fn is_lane_opponent(player_role: &str, enemy_role: &str) -> bool {
+    let bot_lane = |r: &str| r == "BOTTOM" || r == "UTILITY";
+    player_role == enemy_role || (bot_lane(player_role) && bot_lane(enemy_role))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rejects_negative_and_non_finite_weights() {
+        assert!(RoleWeights::parse("1.0,0.8,0.5").is_some());
+        assert!(RoleWeights::parse("1.0,-0.8,0.5").is_none());
+        assert!(RoleWeights::parse("nan,0.8,0.5").is_none());
+        assert!(RoleWeights::parse("1.0,0.8,inf").is_none());
+    }
+}
@@ -0,0 +1,308 @@
+use super::champion_stats::ChampionStats;
+
+/// Strength of the prior, in games, when shrinking win rates toward the
+/// player's overall win rate
+pub const PRIOR_GAMES: f64 = 5.0;
+
+/// Names accepted by `strategy_from_name`
+pub const STRATEGY_NAMES: [&str; 4] = ["weighted", "loss-contribution", "winrate-delta", "frequency"];
+
+/// Totals shared by every champion being scored
+#[derive(Debug, Clone)]
+pub struct ScoringContext {
+    pub total_games: usize,
+    pub total_wins: usize,
+    pub max_recency: f64,
+    /// Weight frequency and win rate by recency too, not just the recency term
+    pub decay_all: bool,
+    pub decayed_games: f64,
+    pub decayed_losses: f64,
+    /// Player deaths to enemy champions, known only with timelines
+    pub total_deaths: usize,
+}
+
+impl ScoringContext {
+    /// `games` holds (won, recency weight) for every analyzed game
+    pub fn new(stats: &[ChampionStats], games: &[(bool, f64)], decay_all: bool) -> Self {
+        let max_recency = stats
+            .iter()
+            .map(|s| s.recency_score)
+            .fold(0.0, f64::max);
+
+        ScoringContext {
+            total_games: games.len(),
+            total_wins: games.iter().filter(|(won, _)| *won).count(),
+            max_recency,
+            decay_all,
+            decayed_games: games.iter().map(|(_, weight)| weight).sum(),
+            decayed_losses: games
+                .iter()
+                .filter(|(won, _)| !*won)
+                .map(|(_, weight)| weight)
+                .sum(),
+            total_deaths: stats.iter().map(|s| s.deaths_caused).sum(),
+        }
+    }
+
+    /// The player's overall win rate over the analyzed games
+    pub fn baseline_win_rate(&self) -> f64 {
+        if self.decay_all && self.decayed_games > 0.0 {
+            1.0 - self.decayed_losses / self.decayed_games
+        } else if self.total_games == 0 {
+            0.5
+        } else {
+            self.total_wins as f64 / self.total_games as f64
+        }
+    }
+
+    /// Share of games (0-1) in which the champion was faced
+    pub fn frequency(&self, stats: &ChampionStats) -> f64 {
+        if self.decay_all {
+            if self.decayed_games > 0.0 {
+                stats.decayed_faced / self.decayed_games
+            } else {
+                0.0
+            }
+        } else {
+            stats.frequency(self.total_games) / 100.0
+        }
+    }
+
+    /// Win rate against the champion, shrunk toward the baseline
+    pub fn adjusted_win_rate(&self, stats: &ChampionStats) -> f64 {
+        if self.decay_all {
+            stats.decayed_shrunk_win_rate(self.baseline_win_rate(), PRIOR_GAMES)
+        } else {
+            stats.shrunk_win_rate(self.baseline_win_rate(), PRIOR_GAMES)
+        }
+    }
+
+    /// Share of the player's losses in which the champion was on the enemy team
+    pub fn loss_share(&self, stats: &ChampionStats) -> f64 {
+        if self.decay_all {
+            if self.decayed_losses > 0.0 {
+                (stats.decayed_faced - stats.decayed_wins) / self.decayed_losses
+            } else {
+                0.0
+            }
+        } else {
+            let total_losses = self.total_games - self.total_wins;
+            if total_losses == 0 {
+                0.0
+            } else {
+                (stats.times_faced - stats.wins_against) as f64 / total_losses as f64
+            }
+        }
+    }
+
+    /// Share (0-1) of the player's deaths to enemy champions dealt by this one
+    pub fn killer_share(&self, stats: &ChampionStats) -> f64 {
+        if self.total_deaths == 0 {
+            0.0
+        } else {
+            stats.deaths_caused as f64 / self.total_deaths as f64
+        }
+    }
+
+    pub fn recency_normalized(&self, stats: &ChampionStats) -> f64 {
+        if self.max_recency > 0.0 {
+            stats.recency_score / self.max_recency
+        } else {
+            0.0
+        }
+    }
+}
+
+/// One additive term of a ban score
+#[derive(Debug, Clone)]
+pub struct ScoreComponent {
+    pub label: String,
+    pub value: f64,
+}
+
+impl ScoreComponent {
+    pub fn new(label: &str, value: f64) -> Self {
+        ScoreComponent {
+            label: label.to_string(),
+            value,
+        }
+    }
+}
+
+/// Ranks enemy champions for banning (higher = ban first)
+pub trait ScoringStrategy {
+    fn name(&self) -> &str;
+
+    /// Human-readable formula, shown next to the ranking
+    fn description(&self) -> String;
+
+    /// The terms that add up to the score
+    fn components(&self, stats: &ChampionStats, ctx: &ScoringContext) -> Vec<ScoreComponent>;
+
+    fn score(&self, stats: &ChampionStats, ctx: &ScoringContext) -> f64 {
+        self.components(stats, ctx).iter().map(|c| c.value).sum()
+    }
+}
+
+/// Weights of the default weighted strategy
+#[derive(Debug, Clone, Copy)]
+pub struct ScoreWeights {
+    pub frequency: f64,
+    pub win_rate: f64,
+    pub recency: f64,
+    /// Only applies when timelines provide death data
+    pub killer: f64,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        ScoreWeights {
+            frequency: 0.4,
+            win_rate: 0.5,
+            recency: 0.1,
+            killer: 0.2,
+        }
+    }
+}
+
+impl ScoreWeights {
+    /// Parse `frequency,win_rate,recency[,killer]` (e.g. `0.4,0.5,0.1`).
+    /// Weights must be finite and non-negative.
+    pub fn parse(input: &str) -> Option<Self> {
+        let values: Vec<f64> = input
+            .split(',')
+            .map(|v| v.trim().parse::<f64>())
+            .collect::<Result<_, _>>()
+            .ok()?;
+        if !values.iter().all(|v| v.is_finite() && *v >= 0.0) {
+            return None;
+        }
+
+        match values.as_slice() {
+            [frequency, win_rate, recency] => Some(ScoreWeights {
+                frequency: *frequency,
+                win_rate: *win_rate,
+                recency: *recency,
+                ..ScoreWeights::default()
+            }),
+            [frequency, win_rate, recency, killer] => Some(ScoreWeights {
+                frequency: *frequency,
+                win_rate: *win_rate,
+                recency: *recency,
+                killer: *killer,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Default: weighted sum of frequency, loss rate and recency, plus the
+/// share of the player's deaths when timelines are loaded
+pub struct WeightedScore {
+    pub weights: ScoreWeights,
+}
+
+impl ScoringStrategy for WeightedScore {
+    fn name(&self) -> &str {
+        "weighted"
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{}×frequency + {}×(1 - win rate) + {}×recency (+ {}×killer share with --timeline)",
+            self.weights.frequency, self.weights.win_rate, self.weights.recency, self.weights.killer
+        )
+    }
+
+    fn components(&self, stats: &ChampionStats, ctx: &ScoringContext) -> Vec<ScoreComponent> {
+        let mut components = vec![
+            ScoreComponent::new("frequency", self.weights.frequency * ctx.frequency(stats)),
+            ScoreComponent::new(
+                "win rate",
+                self.weights.win_rate * (1.0 - ctx.adjusted_win_rate(stats)),
+            ),
+            ScoreComponent::new("recency", self.weights.recency * ctx.recency_normalized(stats)),
+        ];
+        if ctx.total_deaths > 0 {
+            components.push(ScoreComponent::new("killer", self.weights.killer * ctx.killer_share(stats)));
+        }
+        components
+    }
+}
+
+/// Share of all the player's losses in which this champion was on the enemy team
+pub struct LossContribution;
+
+impl ScoringStrategy for LossContribution {
+    fn name(&self) -> &str {
+        "loss-contribution"
+    }
+
+    fn description(&self) -> String {
+        "losses against / your total losses".to_string()
+    }
+
+    fn components(&self, stats: &ChampionStats, ctx: &ScoringContext) -> Vec<ScoreComponent> {
+        vec![ScoreComponent::new("loss share", ctx.loss_share(stats))]
+    }
+}
+
+/// How far below the player's overall win rate they do against this champion
+pub struct WinRateDelta;
+
+impl ScoringStrategy for WinRateDelta {
+    fn name(&self) -> &str {
+        "winrate-delta"
+    }
+
+    fn description(&self) -> String {
+        "your overall win rate - adjusted win rate against".to_string()
+    }
+
+    fn components(&self, stats: &ChampionStats, ctx: &ScoringContext) -> Vec<ScoreComponent> {
+        vec![ScoreComponent::new(
+            "win rate delta",
+            ctx.baseline_win_rate() - ctx.adjusted_win_rate(stats),
+        )]
+    }
+}
+
+/// Ban whatever shows up most
+pub struct FrequencyOnly;
+
+impl ScoringStrategy for FrequencyOnly {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn description(&self) -> String {
+        "times faced / games".to_string()
+    }
+
+    fn components(&self, stats: &ChampionStats, ctx: &ScoringContext) -> Vec<ScoreComponent> {
+        vec![ScoreComponent::new("frequency", ctx.frequency(stats))]
+    }
+}
+
+pub fn strategy_from_name(name: &str, weights: ScoreWeights) -> Option<Box<dyn ScoringStrategy>> {
+    match name.to_ascii_lowercase().as_str() {
+        "weighted" | "default" => Some(Box::new(WeightedScore { weights })),
+        "loss-contribution" | "losses" => Some(Box::new(LossContribution)),
+        "winrate-delta" | "delta" => Some(Box::new(WinRateDelta)),
+        "frequency" | "frequency-only" => Some(Box::new(FrequencyOnly)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rejects_negative_and_non_finite_weights() {
+        assert!(ScoreWeights::parse("0.4,0.5,0.1,0.2").is_some());
+        assert!(ScoreWeights::parse("0.4,-0.5,0.1").is_none());
+        assert!(ScoreWeights::parse("0.4,0.5,nan").is_none());
+        assert!(ScoreWeights::parse("inf,0.5,0.1,0.2").is_none());
+    }
+}
@@ -0,0 +1,114 @@
+use super::champion_stats::significance;
+use crate::api::models::ParticipantDto;
+use std::collections::HashMap;
+
+/// Games together before a pair is reported
+pub const PAIR_MIN_GAMES: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PairKind {
+    /// Your champion with an ally champion
+    WithYou,
+    /// Two ally champions on your team
+    Allies,
+    /// Enemy ADC + support
+    EnemyBotLane,
+    /// Enemy jungler + mid laner
+    EnemyJungleMid,
+}
+
+#[derive(Debug, Clone)]
+pub struct PairStats {
+    pub kind: PairKind,
+    pub first: String,
+    pub second: String,
+    pub games: usize,
+    pub wins: usize,
+}
+
+impl PairStats {
+    pub fn new(kind: PairKind, first: String, second: String) -> Self {
+        PairStats {
+            kind,
+            first,
+            second,
+            games: 0,
+            wins: 0,
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+
+    /// How many standard errors the pair's win rate is from `baseline_win_rate`
+    /// (positive = better than usual)
+    pub fn significance(&self, baseline_win_rate: f64) -> f64 {
+        significance(self.wins, self.games, baseline_win_rate)
+    }
+}
+
+/// Tracks win rates of champion pairs on the player's team
+/// (enemy pairs live in `enemy_pairs`)
+pub struct SynergyTracker {
+    pairs: HashMap<(PairKind, String, String), PairStats>,
+}
+
+impl SynergyTracker {
+    pub fn new() -> Self {
+        SynergyTracker {
+            pairs: HashMap::new(),
+        }
+    }
+
+    fn add_pair(&mut self, kind: PairKind, first: &str, second: &str, won: bool) {
+        let entry = self
+            .pairs
+            .entry((kind, first.to_string(), second.to_string()))
+            .or_insert_with(|| PairStats::new(kind, first.to_string(), second.to_string()));
+
+        entry.games += 1;
+        if won {
+            entry.wins += 1;
+        }
+    }
+
+    pub fn add_game(&mut self, player: &ParticipantDto, participants: &[ParticipantDto]) {
+        let mut allies: Vec<&str> = participants
+            .iter()
+            .filter(|p| p.team_id == player.team_id && p.puuid != player.puuid)
+            .map(|p| p.champion_name.as_str())
+            .collect();
+        allies.sort();
+
+        for (idx, ally) in allies.iter().enumerate() {
+            self.add_pair(PairKind::WithYou, &player.champion_name, ally, player.win);
+            for other in &allies[(idx + 1)..] {
+                self.add_pair(PairKind::Allies, ally, other, player.win);
+            }
+        }
+    }
+
+    /// Pairs of `kind` with at least `PAIR_MIN_GAMES` games, most significant
+    /// (furthest from your usual win rate, either way) first
+    pub fn get_pairs(&self, kind: PairKind, baseline_win_rate: f64) -> Vec<PairStats> {
+        let mut pairs: Vec<PairStats> = self
+            .pairs
+            .values()
+            .filter(|p| p.kind == kind && p.games >= PAIR_MIN_GAMES)
+            .cloned()
+            .collect();
+        pairs.sort_by(|a, b| {
+            b.significance(baseline_win_rate)
+                .abs()
+                .partial_cmp(&a.significance(baseline_win_rate).abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.games.cmp(&a.games))
+        });
+        pairs
+    }
+}
@@ -0,0 +1,155 @@
+use crate::api::models::ParticipantDto;
+use std::collections::HashMap;
+
+/// Games together before a teammate counts as a duo partner
+pub const DUO_MIN_GAMES: usize = 2;
+
+/// A specific player (by PUUID) who was on the player's team
+#[derive(Debug, Clone)]
+pub struct TeammateStats {
+    #[allow(dead_code)]
+    pub puuid: String,
+    pub riot_id: String,
+    pub games: usize,
+    pub wins: usize,
+    pub champions: HashMap<String, usize>,
+}
+
+impl TeammateStats {
+    pub fn new(puuid: String, riot_id: String) -> Self {
+        TeammateStats {
+            puuid,
+            riot_id,
+            games: 0,
+            wins: 0,
+            champions: HashMap::new(),
+        }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games as f64
+        }
+    }
+
+    /// Champions they played with us, most played first
+    pub fn champions(&self) -> Vec<(String, usize)> {
+        let mut champions: Vec<(String, usize)> = self
+            .champions
+            .iter()
+            .map(|(name, count)| (name.clone(), *count))
+            .collect();
+        champions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        champions
+    }
+}
+
+/// Win rate without any duo partner vs with at least one
+#[derive(Debug, Clone, Default)]
+pub struct SoloDuoSplit {
+    pub solo_games: usize,
+    pub solo_wins: usize,
+    pub duo_games: usize,
+    pub duo_wins: usize,
+}
+
+impl SoloDuoSplit {
+    pub fn solo_win_rate(&self) -> f64 {
+        if self.solo_games == 0 {
+            0.0
+        } else {
+            self.solo_wins as f64 / self.solo_games as f64
+        }
+    }
+
+    pub fn duo_win_rate(&self) -> f64 {
+        if self.duo_games == 0 {
+            0.0
+        } else {
+            self.duo_wins as f64 / self.duo_games as f64
+        }
+    }
+}
+
+/// Tracks teammates by PUUID rather than by champion
+pub struct TeammateTracker {
+    teammates: HashMap<String, TeammateStats>,
+    games: Vec<(bool, Vec<String>)>, // (won, teammate PUUIDs)
+}
+
+impl TeammateTracker {
+    pub fn new() -> Self {
+        TeammateTracker {
+            teammates: HashMap::new(),
+            games: Vec::new(),
+        }
+    }
+
+    pub fn add_game(&mut self, player: &ParticipantDto, participants: &[ParticipantDto]) {
+        let mut teammates = Vec::new();
+
+        for p in participants
+            .iter()
+            .filter(|p| p.team_id == player.team_id && p.puuid != player.puuid && !p.puuid.is_empty())
+        {
+            let entry = self
+                .teammates
+                .entry(p.puuid.clone())
+                .or_insert_with(|| TeammateStats::new(p.puuid.clone(), p.riot_id()));
+
+            entry.games += 1;
+            if player.win {
+                entry.wins += 1;
+            }
+            *entry.champions.entry(p.champion_name.clone()).or_insert(0) += 1;
+            teammates.push(p.puuid.clone());
+        }
+
+        self.games.push((player.win, teammates));
+    }
+
+    /// Teammates seen in at least `min_games` games, most games first
+    pub fn duo_partners(&self, min_games: usize) -> Vec<TeammateStats> {
+        let mut partners: Vec<TeammateStats> = self
+            .teammates
+            .values()
+            .filter(|t| t.games >= min_games)
+            .cloned()
+            .collect();
+        partners.sort_by(|a, b| {
+            b.games
+                .cmp(&a.games)
+                .then_with(|| b.win_rate().partial_cmp(&a.win_rate()).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        partners
+    }
+
+    pub fn solo_duo_split(&self, min_games: usize) -> SoloDuoSplit {
+        let mut split = SoloDuoSplit::default();
+
+        for (won, teammates) in &self.games {
+            let with_duo = teammates.iter().any(|puuid| {
+                self.teammates
+                    .get(puuid)
+                    .map(|t| t.games >= min_games)
+                    .unwrap_or(false)
+            });
+
+            if with_duo {
+                split.duo_games += 1;
+                if *won {
+                    split.duo_wins += 1;
+                }
+            } else {
+                split.solo_games += 1;
+                if *won {
+                    split.solo_wins += 1;
+                }
+            }
+        }
+
+        split
+    }
+}
@@ -0,0 +1,184 @@
+use crate::api::models::{ParticipantDto, TimelineDto};
+
+/// Win probability at or above which a team is clearly ahead
+const CLEARLY_AHEAD: f64 = 0.75;
+/// Win probability at or below which a team is clearly behind
+const CLEARLY_BEHIND: f64 = 0.25;
+
+/// Minute and team gold lead used for the "threw a lead" aggregate
+pub const LEAD_MINUTE: usize = 20;
+pub const BIG_LEAD: i32 = 3000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameShape {
+    Stomp,
+    Comeback,
+    Throw,
+    Close,
+}
+
+impl GameShape {
+    pub const ALL: [GameShape; 4] = [GameShape::Stomp, GameShape::Comeback, GameShape::Throw, GameShape::Close];
+
+    pub fn label(&self) -> &str {
+        match self {
+            GameShape::Stomp => "stomp",
+            GameShape::Comeback => "comeback",
+            GameShape::Throw => "throw",
+            GameShape::Close => "close",
+        }
+    }
+}
+
+/// Approximate win probability from the team gold lead. The same lead
+/// matters less as the game goes on and items cap out.
+pub fn win_probability(gold_diff: i32, minute: usize) -> f64 {
+    let scale = 1000.0 + 100.0 * minute as f64;
+    1.0 / (1.0 + (-(gold_diff as f64) / scale).exp())
+}
+
+/// One game seen through the player's team gold lead
+#[derive(Debug, Clone)]
+pub struct GameFlow {
+    pub won: bool,
+    pub gold_diffs: Vec<i32>, // team gold lead per timeline frame (one per minute)
+    pub curve: Vec<f64>,      // win probability per frame
+    pub shape: GameShape,
+}
+
+impl GameFlow {
+    pub fn from_gold_diffs(gold_diffs: Vec<i32>, won: bool) -> Self {
+        let curve: Vec<f64> = gold_diffs
+            .iter()
+            .enumerate()
+            .map(|(minute, diff)| win_probability(*diff, minute))
+            .collect();
+        let peak = curve.iter().copied().fold(0.5, f64::max);
+        let low = curve.iter().copied().fold(0.5, f64::min);
+
+        let shape = match won {
+            true if low <= CLEARLY_BEHIND => GameShape::Comeback,
+            true if peak >= CLEARLY_AHEAD && low >= 0.4 => GameShape::Stomp,
+            false if peak >= CLEARLY_AHEAD => GameShape::Throw,
+            false if low <= CLEARLY_BEHIND && peak <= 0.6 => GameShape::Stomp,
+            _ => GameShape::Close,
+        };
+
+        GameFlow {
+            won,
+            gold_diffs,
+            curve,
+            shape,
+        }
+    }
+
+    pub fn gold_diff_at(&self, minute: usize) -> Option<i32> {
+        self.gold_diffs.get(minute).copied()
+    }
+
+    /// Largest team gold lead and deficit over the game
+    pub fn max_lead(&self) -> i32 {
+        self.gold_diffs.iter().copied().max().unwrap_or(0).max(0)
+    }
+
+    pub fn max_deficit(&self) -> i32 {
+        self.gold_diffs.iter().copied().min().unwrap_or(0).min(0)
+    }
+
+    /// Win probability curve squeezed into at most `width` block characters
+    pub fn sparkline(&self, width: usize) -> String {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        if self.curve.is_empty() || width == 0 {
+            return String::new();
+        }
+
+        let step = self.curve.len().div_ceil(width);
+        self.curve
+            .chunks(step)
+            .map(|chunk| {
+                let p = chunk.iter().sum::<f64>() / chunk.len() as f64;
+                BARS[((p * BARS.len() as f64) as usize).min(BARS.len() - 1)]
+            })
+            .collect()
+    }
+}
+
+/// Team gold lead per frame for the player's team; `None` if the timeline
+/// can't be matched to the participants
+pub fn game_flow(
+    timeline: &TimelineDto,
+    player: &ParticipantDto,
+    participants: &[ParticipantDto],
+) -> Option<GameFlow> {
+    let team_of = |participant_id: &str| -> Option<i32> {
+        let id: i32 = participant_id.parse().ok()?;
+        let puuid = &timeline
+            .info
+            .participants
+            .iter()
+            .find(|p| p.participant_id == id)?
+            .puuid;
+        participants.iter().find(|p| &p.puuid == puuid).map(|p| p.team_id)
+    };
+
+    let gold_diffs: Vec<i32> = timeline
+        .info
+        .frames
+        .iter()
+        .map(|frame| {
+            frame
+                .participant_frames
+                .iter()
+                .map(|(id, pf)| match team_of(id) {
+                    Some(team) if team == player.team_id => pf.total_gold,
+                    Some(_) => -pf.total_gold,
+                    None => 0,
+                })
+                .sum()
+        })
+        .collect();
+
+    if gold_diffs.is_empty() {
+        None
+    } else {
+        Some(GameFlow::from_gold_diffs(gold_diffs, player.win))
+    }
+}
+
+/// (wins, losses) per shape
+pub fn shape_counts(flows: &[&GameFlow]) -> Vec<(GameShape, usize, usize)> {
+    GameShape::ALL
+        .iter()
+        .map(|shape| {
+            let games = flows.iter().filter(|f| f.shape == *shape);
+            let wins = games.clone().filter(|f| f.won).count();
+            (*shape, wins, games.count() - wins)
+        })
+        .collect()
+}
+
+/// Games lost despite a `lead` team gold lead at `minute`
+pub fn thrown_leads(flows: &[&GameFlow], minute: usize, lead: i32) -> usize {
+    flows
+        .iter()
+        .filter(|f| !f.won && f.gold_diff_at(minute).is_some_and(|d| d >= lead))
+        .count()
+}
+
+/// Games won despite a `deficit` team gold deficit at `minute`
+pub fn comebacks_from(flows: &[&GameFlow], minute: usize, deficit: i32) -> usize {
+    flows
+        .iter()
+        .filter(|f| f.won && f.gold_diff_at(minute).is_some_and(|d| d <= -deficit))
+        .count()
+}
+
+/// (games with the lead, games won) for a `lead` team gold lead at `minute`
+pub fn lead_conversion(flows: &[&GameFlow], minute: usize, lead: i32) -> (usize, usize) {
+    let ahead: Vec<&&GameFlow> = flows
+        .iter()
+        .filter(|f| f.gold_diff_at(minute).is_some_and(|d| d >= lead))
+        .collect();
+    let converted = ahead.iter().filter(|f| f.won).count();
+    (ahead.len(), converted)
+}
@@ -1,3 +1,4 @@
+use crate::cache::{DataDragonCache, TimelineCache};
 use crate::config::Config;
 use crate::error::AppError;
 use governor::{Quota, RateLimiter, state::{InMemoryState, NotKeyed}, clock::DefaultClock};
@@ -7,8 +8,12 @@ use std::time::Duration;
 
 use super::models::*;
 
+/// Data Dragon version the champion and item data is read from
+const DATA_DRAGON_VERSION: &str = "14.25.1";
+
 pub struct RiotApiClient {
     config: Config,
+    #[allow(dead_code)]
     rate_limiter: RateLimiter<NotKeyed, InMemoryState, DefaultClock>,
 }
 
@@ -92,6 +97,7 @@ impl RiotApiClient {
         })
     }
 
+    #[allow(dead_code)]
     pub fn get_league_entry(&self, summoner_id: &str) -> Result<LeagueEntryDto, AppError> {
         let url = format!(
             "https://{}.api.riotgames.com/lol/league/v4/entries/by-summoner/{}?api_key={}",
@@ -104,6 +110,18 @@ impl RiotApiClient {
         })
     }
 
+    pub fn get_top_masteries(&self, puuid: &str, count: usize) -> Result<Vec<ChampionMasteryDto>, AppError> {
+        let url = format!(
+            "https://{}.api.riotgames.com/lol/champion-mastery/v4/champion-masteries/by-puuid/{}/top?count={}&api_key={}",
+            self.config.region, puuid, count, self.config.api_key
+        );
+
+        let body = self.execute_request(&url)?;
+        serde_json::from_str(&body).map_err(|e| {
+            AppError::JsonError(e.to_string())
+        })
+    }
+
     pub fn get_match_ids(&self, puuid: &str, count: usize, queue: &str) -> Result<Vec<String>, AppError> {
         let regional_routing = self.get_regional_routing();
         let url = if queue == "all" {
@@ -137,19 +155,65 @@ impl RiotApiClient {
         })
     }
 
-    #[allow(dead_code)]
+    /// Match timeline, served from the disk cache when available. The flag
+    /// is true when the API was called, whether or not the call succeeded.
+    pub fn get_match_timeline(&self, match_id: &str) -> (Result<TimelineDto, AppError>, bool) {
+        if let Some(body) = TimelineCache::load(match_id) {
+            if let Ok(timeline) = serde_json::from_str(&body) {
+                return (Ok(timeline), false);
+            }
+        }
+
+        let regional_routing = self.get_regional_routing();
+        let url = format!(
+            "https://{}.api.riotgames.com/lol/match/v5/matches/{}/timeline?api_key={}",
+            regional_routing, match_id, self.config.api_key
+        );
+
+        let result = self.execute_request(&url).and_then(|body| {
+            let timeline = serde_json::from_str(&body).map_err(|e| {
+                AppError::JsonError(e.to_string())
+            })?;
+            let _ = TimelineCache::save(match_id, &body);
+            Ok(timeline)
+        });
+
+        (result, true)
+    }
+
     pub fn get_champion_data(&self) -> Result<DataDragonChampions, AppError> {
-        let url = "https://ddragon.leagueoflegends.com/cdn/14.25.1/data/en_US/champion.json";
+        self.get_data_dragon_file("champion.json")
+    }
+
+    pub fn get_item_data(&self) -> Result<DataDragonItems, AppError> {
+        self.get_data_dragon_file("item.json")
+    }
 
-        let body = ureq::get(url)
+    /// A Data Dragon data file, served from the disk cache when available
+    fn get_data_dragon_file<T: serde::de::DeserializeOwned>(&self, file: &str) -> Result<T, AppError> {
+        if let Some(body) = DataDragonCache::load(DATA_DRAGON_VERSION, file) {
+            if let Ok(data) = serde_json::from_str(&body) {
+                return Ok(data);
+            }
+        }
+
+        let url = format!(
+            "https://ddragon.leagueoflegends.com/cdn/{}/data/en_US/{}",
+            DATA_DRAGON_VERSION, file
+        );
+
+        let body = ureq::get(&url)
             .set("User-Agent", "league_detect/0.1.0")
             .call()
             .map_err(|e| AppError::HttpError(e.to_string()))?
             .into_string()
             .map_err(|e| AppError::HttpError(e.to_string()))?;
 
-        serde_json::from_str(&body).map_err(|e| {
+        let data = serde_json::from_str(&body).map_err(|e| {
             AppError::JsonError(e.to_string())
-        })
+        })?;
+        let _ = DataDragonCache::save(DATA_DRAGON_VERSION, file, &body);
+
+        Ok(data)
     }
 }
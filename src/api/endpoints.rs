@@ -8,8 +8,14 @@ pub const SUMMONER_ENDPOINT: &str = "https://{region}.api.riotgames.com/lol/summ
 #[allow(dead_code)]
 pub const LEAGUE_ENDPOINT: &str = "https://{region}.api.riotgames.com/lol/league/v4/entries/by-summoner";
 #[allow(dead_code)]
+pub const MASTERY_ENDPOINT: &str = "https://{region}.api.riotgames.com/lol/champion-mastery/v4/champion-masteries/by-puuid";
+#[allow(dead_code)]
 pub const MATCH_IDS_ENDPOINT: &str = "https://{region}.api.riotgames.com/lol/match/v5/matches/by-puuid";
 #[allow(dead_code)]
 pub const MATCH_ENDPOINT: &str = "https://{region}.api.riotgames.com/lol/match/v5/matches";
 #[allow(dead_code)]
+pub const TIMELINE_ENDPOINT: &str = "https://{region}.api.riotgames.com/lol/match/v5/matches/{matchId}/timeline";
+#[allow(dead_code)]
 pub const DATA_DRAGON_ENDPOINT: &str = "https://ddragon.leagueoflegends.com/cdn/{version}/data/en_US/champion.json";
+#[allow(dead_code)]
+pub const DATA_DRAGON_ITEM_ENDPOINT: &str = "https://ddragon.leagueoflegends.com/cdn/{version}/data/en_US/item.json";
@@ -1,4 +1,5 @@
 use serde::Deserialize;
+use std::collections::HashMap;
 
 // Account V1 response
 #[derive(Debug, Deserialize)]
@@ -40,6 +41,16 @@ pub struct LeagueEntryDto {
     pub losses: i32,
 }
 
+// Champion Mastery V4 response
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ChampionMasteryDto {
+    pub champion_id: i64,
+    pub champion_level: i32,
+    pub champion_points: i64,
+}
+
 // Match V5 response
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -66,12 +77,87 @@ pub struct MatchInfo {
     pub participants: Vec<ParticipantDto>,
     #[serde(default)]
     pub game_id: i64,
+    #[serde(default)]
+    pub game_creation: i64, // epoch ms
+    #[serde(default)]
+    pub game_end_timestamp: i64, // epoch ms, missing on matches before patch 11.20
+    #[serde(default)]
+    pub teams: Vec<TeamDto>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TeamDto {
+    pub team_id: i32,
+    pub win: bool,
+    #[serde(default)]
+    pub objectives: ObjectivesDto,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ObjectivesDto {
+    #[serde(default)]
+    pub baron: ObjectiveDto,
+    #[serde(default)]
+    pub champion: ObjectiveDto, // first = first blood
+    #[serde(default)]
+    pub dragon: ObjectiveDto,
+    #[serde(default)]
+    pub horde: ObjectiveDto, // void grubs
+    #[serde(default)]
+    pub inhibitor: ObjectiveDto,
+    #[serde(default)]
+    pub rift_herald: ObjectiveDto,
+    #[serde(default)]
+    pub tower: ObjectiveDto,
+}
+
+#[derive(Debug, Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectiveDto {
+    #[serde(default)]
+    pub first: bool,
+    #[serde(default)]
+    pub kills: i32,
+}
+
+impl MatchInfo {
+    /// Game length in seconds (older matches report milliseconds)
+    pub fn duration_secs(&self) -> i64 {
+        if self.game_end_timestamp == 0 && self.game_duration > 100_000 {
+            self.game_duration / 1000
+        } else {
+            self.game_duration
+        }
+    }
+
+    pub fn duration_minutes(&self) -> f64 {
+        self.duration_secs() as f64 / 60.0
+    }
+
+    /// When the game ended, if the match carries enough timing data
+    pub fn ended_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let end_ms = if self.game_end_timestamp > 0 {
+            self.game_end_timestamp
+        } else if self.game_creation > 0 {
+            self.game_creation + self.duration_secs() * 1000
+        } else {
+            return None;
+        };
+
+        chrono::DateTime::from_timestamp_millis(end_ms)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct ParticipantDto {
+    #[serde(default)]
+    pub participant_id: i32,
     pub puuid: String,
     pub champion_id: i32,
     pub champion_name: String,
@@ -81,6 +167,326 @@ pub struct ParticipantDto {
     pub lane: String,  // TOP, JUNGLE, MIDDLE, BOTTOM, UTILITY
     #[serde(default)]
     pub role: String,  // TOP, JUNGLE, MID, ADC, SUPPORT
+    #[serde(default)]
+    pub team_position: String,  // TOP, JUNGLE, MIDDLE, BOTTOM, UTILITY (empty outside SR)
+    #[serde(default)]
+    pub kills: i32,
+    #[serde(default)]
+    pub deaths: i32,
+    #[serde(default)]
+    pub assists: i32,
+    #[serde(default)]
+    pub gold_earned: i32,
+    #[serde(default)]
+    pub total_minions_killed: i32,
+    #[serde(default)]
+    pub neutral_minions_killed: i32,
+    #[serde(default)]
+    pub champ_experience: i32,
+    #[serde(default)]
+    pub vision_score: i32,
+    #[serde(default)]
+    pub item0: i32,
+    #[serde(default)]
+    pub item1: i32,
+    #[serde(default)]
+    pub item2: i32,
+    #[serde(default)]
+    pub item3: i32,
+    #[serde(default)]
+    pub item4: i32,
+    #[serde(default)]
+    pub item5: i32,
+    #[serde(default)]
+    pub item6: i32, // trinket slot
+    #[serde(default)]
+    pub total_damage_dealt_to_champions: i64,
+    #[serde(default)]
+    pub physical_damage_dealt_to_champions: i64,
+    #[serde(default)]
+    pub magic_damage_dealt_to_champions: i64,
+    #[serde(default)]
+    pub true_damage_dealt_to_champions: i64,
+    #[serde(default)]
+    pub riot_id_game_name: String,
+    #[serde(default)]
+    pub riot_id_tagline: String,
+}
+
+impl ParticipantDto {
+    /// Final inventory in slot order, trinket and empty slots excluded
+    pub fn items(&self) -> Vec<i32> {
+        [self.item0, self.item1, self.item2, self.item3, self.item4, self.item5]
+            .into_iter()
+            .filter(|id| *id != 0)
+            .collect()
+    }
+
+    /// `Name#TAG`, or a shortened PUUID when the match has no Riot ID
+    pub fn riot_id(&self) -> String {
+        if self.riot_id_game_name.is_empty() {
+            let short: String = self.puuid.chars().take(8).collect();
+            format!("{}…", short)
+        } else {
+            format!("{}#{}", self.riot_id_game_name, self.riot_id_tagline)
+        }
+    }
+
+    /// Lane minions plus jungle monsters
+    pub fn creep_score(&self) -> i32 {
+        self.total_minions_killed + self.neutral_minions_killed
+    }
+}
+
+impl MatchDto {
+    /// Locate a player among the participants.
+    ///
+    /// Tries `info.participants` by PUUID first, then the PUUID's position in
+    /// `metadata.participants`, then the Riot ID (case-insensitive).
+    pub fn find_participant(
+        &self,
+        puuid: &str,
+        game_name: &str,
+        tag_line: &str,
+    ) -> Option<&ParticipantDto> {
+        if let Some(p) = self.info.participants.iter().find(|p| p.puuid == puuid) {
+            return Some(p);
+        }
+
+        if let Some(idx) = self.metadata.participants.iter().position(|p| p == puuid) {
+            if let Some(p) = self.info.participants.get(idx) {
+                return Some(p);
+            }
+        }
+
+        self.info.participants.iter().find(|p| {
+            !p.riot_id_game_name.is_empty()
+                && p.riot_id_game_name.eq_ignore_ascii_case(game_name)
+                && p.riot_id_tagline.eq_ignore_ascii_case(tag_line)
+        })
+    }
+}
+
+// Match V5 timeline response
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct TimelineDto {
+    pub metadata: MatchMetadata,
+    pub info: TimelineInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TimelineInfo {
+    #[serde(default)]
+    pub frame_interval: i64, // ms, usually 60000
+    pub frames: Vec<FrameDto>,
+    #[serde(default)]
+    pub participants: Vec<TimelineParticipantDto>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct TimelineParticipantDto {
+    pub participant_id: i32,
+    pub puuid: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct FrameDto {
+    pub timestamp: i64, // ms since game start
+    #[serde(default)]
+    pub participant_frames: HashMap<String, ParticipantFrameDto>, // keyed by participant ID
+    #[serde(default)]
+    pub events: Vec<EventDto>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct ParticipantFrameDto {
+    pub participant_id: i32,
+    #[serde(default)]
+    pub total_gold: i32,
+    #[serde(default)]
+    pub current_gold: i32,
+    #[serde(default)]
+    pub xp: i32,
+    #[serde(default)]
+    pub level: i32,
+    #[serde(default)]
+    pub minions_killed: i32,
+    #[serde(default)]
+    pub jungle_minions_killed: i32,
+    #[serde(default)]
+    pub position: Option<PositionDto>,
+}
+
+impl ParticipantFrameDto {
+    pub fn creep_score(&self) -> i32 {
+        self.minions_killed + self.jungle_minions_killed
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[allow(dead_code)]
+pub struct PositionDto {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Timeline events we care about; everything else parses as `Other`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(dead_code)]
+pub enum EventDto {
+    #[serde(rename_all = "camelCase")]
+    ChampionKill {
+        timestamp: i64,
+        #[serde(default)]
+        killer_id: i32, // 0 = executed (tower, minions, monsters)
+        victim_id: i32,
+        #[serde(default)]
+        assisting_participant_ids: Vec<i32>,
+        #[serde(default)]
+        position: Option<PositionDto>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ChampionSpecialKill {
+        timestamp: i64,
+        #[serde(default)]
+        kill_type: String, // KILL_FIRST_BLOOD, KILL_MULTI, KILL_ACE
+        #[serde(default)]
+        killer_id: i32,
+    },
+    #[serde(rename_all = "camelCase")]
+    EliteMonsterKill {
+        timestamp: i64,
+        #[serde(default)]
+        killer_id: i32,
+        #[serde(default)]
+        killer_team_id: i32,
+        monster_type: String, // DRAGON, BARON_NASHOR, RIFTHERALD, HORDE, ATAKHAN
+        #[serde(default)]
+        monster_sub_type: String, // e.g. FIRE_DRAGON, ELDER_DRAGON
+    },
+    #[serde(rename_all = "camelCase")]
+    DragonSoulGiven {
+        timestamp: i64,
+        #[serde(default)]
+        team_id: i32,
+        #[serde(default)]
+        name: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    BuildingKill {
+        timestamp: i64,
+        #[serde(default)]
+        killer_id: i32,
+        #[serde(default)]
+        team_id: i32, // team that lost the building
+        #[serde(default)]
+        building_type: String, // TOWER_BUILDING, INHIBITOR_BUILDING
+        #[serde(default)]
+        tower_type: String,
+        #[serde(default)]
+        lane_type: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemPurchased {
+        timestamp: i64,
+        participant_id: i32,
+        item_id: i32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemSold {
+        timestamp: i64,
+        participant_id: i32,
+        item_id: i32,
+    },
+    #[serde(rename_all = "camelCase")]
+    ItemUndo {
+        timestamp: i64,
+        participant_id: i32,
+        #[serde(default)]
+        before_id: i32,
+        #[serde(default)]
+        after_id: i32,
+    },
+    #[serde(rename_all = "camelCase")]
+    LevelUp {
+        timestamp: i64,
+        participant_id: i32,
+        level: i32,
+    },
+    #[serde(rename_all = "camelCase")]
+    WardPlaced {
+        timestamp: i64,
+        #[serde(default)]
+        creator_id: i32,
+        #[serde(default)]
+        ward_type: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    WardKill {
+        timestamp: i64,
+        #[serde(default)]
+        killer_id: i32,
+        #[serde(default)]
+        ward_type: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl TimelineDto {
+    /// Timeline participant ID (1-10) of a PUUID
+    pub fn participant_id(&self, puuid: &str) -> Option<i32> {
+        self.info
+            .participants
+            .iter()
+            .find(|p| p.puuid == puuid)
+            .map(|p| p.participant_id)
+    }
+
+    /// First frame at or after `minute`; `None` if the game ended earlier
+    pub fn frame_at(&self, minute: i64) -> Option<&FrameDto> {
+        self.info.frames.iter().find(|f| f.timestamp >= minute * 60_000)
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &EventDto> {
+        self.info.frames.iter().flat_map(|f| f.events.iter())
+    }
+}
+
+// Data Dragon Item response
+#[derive(Debug, Deserialize)]
+pub struct DataDragonItems {
+    pub data: HashMap<String, ItemInfo>, // keyed by item ID
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[allow(dead_code)]
+pub struct ItemInfo {
+    pub name: String,
+    #[serde(default)]
+    pub into: Vec<String>, // items this builds into (empty = final item)
+    #[serde(default)]
+    pub tags: Vec<String>, // e.g. Boots, Consumable, Trinket
+    #[serde(default)]
+    pub depth: i32,
+    #[serde(default)]
+    pub gold: ItemGold,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ItemGold {
+    #[serde(default)]
+    pub total: i32,
 }
 
 // Data Dragon Champion response
@@ -96,4 +502,6 @@ pub struct ChampionInfo {
     pub id: String,
     pub name: String,
     pub key: String,
+    #[serde(default)]
+    pub tags: Vec<String>, // Assassin, Fighter, Mage, Marksman, Support, Tank (primary first)
 }
@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use chrono::{DateTime, Utc};
@@ -11,7 +10,10 @@ pub struct CachedMatch {
     pub champion: String,
     pub won: bool,
     pub enemies: Vec<String>,
-    pub timestamp: DateTime<Utc>,
+    pub timestamp: DateTime<Utc>, // game end when known, else when it was fetched
+    /// When the game ended; missing on entries cached before this was tracked
+    #[serde(default)]
+    pub ended_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -96,21 +98,26 @@ impl MatchCache {
 
     pub fn add_matches(&mut self, new_matches: Vec<CachedMatch>) {
         // Remove duplicates and add new matches
-        let existing_ids: std::collections::HashSet<_> =
-            self.matches.iter().map(|m| m.id.clone()).collect();
-
         for new_match in new_matches {
-            if !existing_ids.contains(&new_match.id) {
-                self.matches.push(new_match);
+            match self.matches.iter_mut().find(|m| m.id == new_match.id) {
+                // Older entries hold the fetch time; replace it with the real end time
+                Some(existing) => {
+                    if let Some(ended_at) = new_match.ended_at {
+                        existing.timestamp = ended_at;
+                        existing.ended_at = Some(ended_at);
+                    }
+                }
+                None => self.matches.push(new_match),
             }
         }
 
         // Keep most recent matches first
-        self.matches.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        self.matches.sort_by_key(|m| std::cmp::Reverse(m.timestamp));
 
         self.last_updated = Utc::now();
     }
 
+    #[allow(dead_code)]
     pub fn get_recent_matches(&self, count: usize) -> Vec<CachedMatch> {
         self.matches.iter()
             .take(count)
@@ -118,9 +125,65 @@ impl MatchCache {
             .collect()
     }
 
+    #[allow(dead_code)]
     pub fn is_stale(&self, max_age_mins: u64) -> bool {
         let now = Utc::now();
         let age = now.signed_duration_since(self.last_updated);
         age.num_minutes() > max_age_mins as i64
     }
 }
+
+/// Raw match timelines, one file per match. Timelines never change once a
+/// game is over, so they are kept indefinitely.
+pub struct TimelineCache;
+
+impl TimelineCache {
+    pub fn get_timeline_path(match_id: &str) -> PathBuf {
+        let cache_dir = dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".league_detect")
+            .join("timelines");
+
+        let _ = fs::create_dir_all(&cache_dir);
+
+        cache_dir.join(format!("{}.json", match_id))
+    }
+
+    pub fn load(match_id: &str) -> Option<String> {
+        fs::read_to_string(Self::get_timeline_path(match_id)).ok()
+    }
+
+    pub fn save(match_id: &str, body: &str) -> Result<(), AppError> {
+        fs::write(Self::get_timeline_path(match_id), body).map_err(|e| {
+            AppError::JsonError(format!("Failed to write timeline cache: {}", e))
+        })
+    }
+}
+
+/// Data Dragon files for one game version. A version's files never change,
+/// so they are kept indefinitely.
+pub struct DataDragonCache;
+
+impl DataDragonCache {
+    pub fn get_file_path(version: &str, file: &str) -> PathBuf {
+        let cache_dir = dirs::home_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".league_detect")
+            .join("ddragon")
+            .join(version);
+
+        let _ = fs::create_dir_all(&cache_dir);
+
+        cache_dir.join(file)
+    }
+
+    pub fn load(version: &str, file: &str) -> Option<String> {
+        fs::read_to_string(Self::get_file_path(version, file)).ok()
+    }
+
+    pub fn save(version: &str, file: &str, body: &str) -> Result<(), AppError> {
+        fs::write(Self::get_file_path(version, file), body).map_err(|e| {
+            AppError::JsonError(format!("Failed to write Data Dragon cache: {}", e))
+        })
+    }
+}
@@ -5,6 +5,9 @@ use std::env;
 pub struct Config {
     pub api_key: String,
     pub region: String,
+    pub ban_strategy: Option<String>,
+    pub ban_weights: Option<String>,
+    pub protected_champions: Option<String>,
 }
 
 impl Config {
@@ -19,6 +22,16 @@ impl Config {
 
         let region = env::var("RIOT_REGION").unwrap_or_else(|_| "na1".to_string());
 
-        Ok(Config { api_key, region })
+        let ban_strategy = env::var("BAN_STRATEGY").ok();
+        let ban_weights = env::var("BAN_WEIGHTS").ok();
+        let protected_champions = env::var("PROTECTED_CHAMPIONS").ok();
+
+        Ok(Config {
+            api_key,
+            region,
+            ban_strategy,
+            ban_weights,
+            protected_champions,
+        })
     }
 }
@@ -1,6 +1,32 @@
-use crate::analysis::recommender::{BanRecommendation, AllyAnalysis};
+use crate::analysis::archetypes::{ArchetypeStats, TeamSide};
+use crate::analysis::backtest::{BacktestResult, MIN_HISTORY};
+use crate::analysis::champion_pool::{ChampionPoolStats, PoolVerdict};
+use crate::analysis::counters::CounterPick;
+use crate::analysis::damage::{DamageProfile, DamageTracker};
+use crate::analysis::deaths::{DeathTracker, EXECUTED};
+use crate::analysis::enemy_pairs::{is_dangerous, pair_label};
+use crate::analysis::items::{ChampionItems, CORE_ITEMS};
+use crate::analysis::lane_diffs::{LaneDiffs, CHECKPOINTS};
+use crate::analysis::matchups::MatchupStats;
+use crate::analysis::metrics::{GameMetrics, MetricAverages};
+use crate::analysis::objectives::{Objective, ObjectiveStats};
+use crate::analysis::opponents::OpponentStats;
+use crate::analysis::picks::{PickBans, MIN_PICK_GAMES};
+use crate::analysis::protection::ProtectList;
+use crate::analysis::recommender::{apply_role_factor, BanRecommendation, AllyAnalysis};
+use crate::analysis::roles::role_label;
+use crate::analysis::scoring::ScoringStrategy;
+use crate::analysis::synergy::{PairKind, PairStats};
+use crate::analysis::teammates::{SoloDuoSplit, TeammateStats};
+use crate::analysis::win_probability::{
+    comebacks_from, lead_conversion, shape_counts, thrown_leads, GameFlow, GameShape, BIG_LEAD, LEAD_MINUTE,
+};
 use colored::*;
-use tabled::{settings::Style, Table, Tabled};
+use tabled::{
+    builder::Builder,
+    settings::{location::ByColumnName, Disable, Style},
+    Table, Tabled,
+};
 
 #[derive(Tabled)]
 struct MatchRow {
@@ -8,18 +34,129 @@ struct MatchRow {
     number: String,
     champion: String,
     result: String,
+    #[tabled(rename = "KP")]
+    kill_participation: String,
+    #[tabled(rename = "dmg%")]
+    damage_share: String,
+    #[tabled(rename = "gold%")]
+    gold_share: String,
+    #[tabled(rename = "vis/min")]
+    vision_per_min: String,
+    #[tabled(rename = "cs/min")]
+    cs_per_min: String,
+    deaths: String,
     enemies: String,
+    flow: String,
 }
 
 #[derive(Tabled)]
 struct BanRow {
     rank: String,
     champion: String,
+    role: String,
     frequency: String,
     win_rate: String,
+    #[tabled(rename = "95% CI")]
+    confidence: String,
+    #[tabled(rename = "adj. win_rate")]
+    adjusted_win_rate: String,
     score: String,
 }
 
+#[derive(Tabled)]
+struct PickBanRow {
+    rank: String,
+    champion: String,
+    role: String,
+    win_rate: String,
+    score: String,
+}
+
+#[derive(Tabled)]
+struct PoolRow {
+    champion: String,
+    games: String,
+    win_rate: String,
+    kda: String,
+    #[tabled(rename = "cs/min")]
+    cs_per_min: String,
+    #[tabled(rename = "dmg share")]
+    damage_share: String,
+    form: String,
+    trend: String,
+    verdict: String,
+}
+
+#[derive(Tabled)]
+struct CounterRow {
+    rank: String,
+    champion: String,
+    record: String,
+    #[tabled(rename = "adj. win_rate")]
+    adjusted_win_rate: String,
+    #[tabled(rename = "95% CI")]
+    confidence: String,
+    basis: String,
+}
+
+#[derive(Tabled)]
+struct DuoRow {
+    partner: String,
+    games: String,
+    win_rate: String,
+    champions: String,
+}
+
+#[derive(Tabled)]
+struct OpponentRow {
+    player: String,
+    met: String,
+    #[tabled(rename = "your record")]
+    record: String,
+    champions: String,
+    #[tabled(rename = "last encounter")]
+    last_encounter: String,
+    hint: String,
+}
+
+#[derive(Tabled)]
+struct PairRow {
+    pair: String,
+    games: String,
+    win_rate: String,
+    #[tabled(rename = "vs usual")]
+    significance: String,
+}
+
+#[derive(Tabled)]
+struct EnemyPairRow {
+    pair: String,
+    lanes: String,
+    met: String,
+    #[tabled(rename = "your record")]
+    record: String,
+    loss_rate: String,
+    threat: String,
+}
+
+#[derive(Tabled)]
+struct ClassThreatRow {
+    class: String,
+    faced: String,
+    win_rate: String,
+    score: String,
+}
+
+#[derive(Tabled)]
+struct ArchetypeRow {
+    comp: String,
+    side: String,
+    games: String,
+    win_rate: String,
+    #[tabled(rename = "vs usual")]
+    significance: String,
+}
+
 #[derive(Tabled)]
 struct AllyRow {
     rank: String,
@@ -28,9 +165,52 @@ struct AllyRow {
     win_rate: String,
 }
 
+#[derive(Tabled)]
+struct ObjectiveRow {
+    objective: String,
+    #[tabled(rename = "taken")]
+    control: String,
+    #[tabled(rename = "WR taken")]
+    win_rate_secured: String,
+    #[tabled(rename = "WR lost")]
+    win_rate_conceded: String,
+    #[tabled(rename = "vs usual")]
+    significance: String,
+}
+
+#[derive(Tabled)]
+struct MatchupRow {
+    matchup: String,
+    role: String,
+    record: String,
+    win_rate: String,
+    #[tabled(rename = "gold Δ")]
+    gold_diff: String,
+    #[tabled(rename = "cs Δ")]
+    cs_diff: String,
+    #[tabled(rename = "xp Δ")]
+    xp_diff: String,
+    kda: String,
+}
+
+#[derive(Tabled)]
+struct BacktestRow {
+    strategy: String,
+    games: String,
+    #[tabled(rename = "loss hit rate")]
+    loss_hit_rate: String,
+    coverage: String,
+    #[tabled(rename = "est. WR impact")]
+    win_rate_impact: String,
+}
+
 pub fn display_ban_recommendations(
     recommendations: Vec<BanRecommendation>,
     player_name: &str,
+    player_role: Option<&str>,
+    strategy: &dyn ScoringStrategy,
+    unranked_hints: &[(String, String)],
+    explain: bool,
 ) {
     println!(
         "\n{}",
@@ -40,11 +220,23 @@ pub fn display_ban_recommendations(
     );
     println!("{}\n", "=".repeat(60).cyan());
 
+    println!(
+        "Ranked by: {} ({})",
+        strategy.name().bold(),
+        strategy.description()
+    );
+    match player_role {
+        Some(role) => println!("Weighted for role: {}\n", role_label(role).bold()),
+        None => println!("Weighted for the role you played in each game (teamPosition)\n"),
+    }
+
     if recommendations.is_empty() {
         println!(
             "{}",
             "No ban recommendations available (not enough data)".yellow()
         );
+        println!();
+        display_unranked_hints(unranked_hints);
         return;
     }
 
@@ -52,15 +244,22 @@ pub fn display_ban_recommendations(
     for (idx, rec) in recommendations.iter().enumerate() {
         let rank = format!("#{}", idx + 1);
         let champion = rec.champion_name.clone();
+        let role = format_roles(&rec.roles);
         let frequency = format!("{:.1}%", rec.frequency);
-        let win_rate = format!("{:.1}%", rec.win_rate * 100.0);
+        let win_rate = format!("{:.1}% (n={})", rec.win_rate * 100.0, rec.times_faced);
+        let (low, high) = rec.confidence_interval;
+        let confidence = format!("{:.0}–{:.0}%", low * 100.0, high * 100.0);
+        let adjusted_win_rate = format!("{:.1}%", rec.adjusted_win_rate * 100.0);
         let score = format!("{:.2}", rec.score);
 
         rows.push(BanRow {
             rank,
             champion,
+            role,
             frequency,
             win_rate,
+            confidence,
+            adjusted_win_rate,
             score,
         });
     }
@@ -70,40 +269,264 @@ pub fn display_ban_recommendations(
     println!("{}", table);
 
     println!("\n{}", "Interpretation".bold().yellow());
-    println!(
-        "{}",
-        "• Frequency: How often this champion appeared in your last 20 games"
-    );
+    println!("• Frequency: How often this champion appeared in your last 20 games");
+    println!("• Role: Where this champion was played against you (games)");
     println!("• Win Rate: Your win rate when facing this champion");
-    println!("• Score: Combined metric (higher = more dangerous to your rank)\n");
+    println!("• 95% CI: Plausible range for that win rate given the sample size");
+    println!("• Adj. Win Rate: Win rate pulled toward your overall win rate (used for scoring)");
+    println!("• Score: Combined metric (higher = more dangerous to your rank)");
+    if !explain {
+        println!("  Run with --explain for a per-champion score breakdown");
+    }
+    println!();
+
+    let noted: Vec<&BanRecommendation> = recommendations.iter().filter(|r| !r.notes.is_empty()).collect();
+    if !noted.is_empty() {
+        println!("{}", "Notes".bold().yellow());
+        for rec in noted {
+            for note in &rec.notes {
+                println!("  {} {}: {}", "⚠️".red(), rec.champion_name.bold(), note);
+            }
+        }
+        println!();
+    }
+
+    display_unranked_hints(unranked_hints);
 
     // Detailed reasoning for top 1 ban
     if let Some(top_ban) = recommendations.first() {
         println!("{}", "Top Priority Ban".bold().red());
         println!(
-            "  {} faced {}/20 games ({:.1}%) with {:.1}% win rate",
+            "  {} faced in {} games ({:.1}%) with {:.1}% win rate",
             top_ban.champion_name, top_ban.times_faced, top_ban.frequency, top_ban.win_rate * 100.0
         );
-        if top_ban.win_rate < 0.33 {
-            println!(
-                "  {} High threat - very low win rate",
-                "⚠️".red()
-            );
-        } else if top_ban.frequency > 25.0 {
+        if let Some(driver) = top_ban
+            .components
+            .iter()
+            .max_by(|a, b| a.value.partial_cmp(&b.value).unwrap_or(std::cmp::Ordering::Equal))
+        {
             println!(
-                "  {} Very common in your elo",
-                "🔥".red()
+                "  {} Driven mostly by {} (+{:.2} of {:.2})",
+                "⚠️".red(),
+                driver.label,
+                apply_role_factor(driver.value, top_ban.role_factor),
+                top_ban.score
             );
         }
     }
 
     println!();
+
+    if explain {
+        display_ban_explanations(&recommendations, strategy);
+    }
+}
+
+/// Hints for champions that did not make the ban list
+fn display_unranked_hints(hints: &[(String, String)]) {
+    if hints.is_empty() {
+        return;
+    }
+
+    println!("{}", "Also Consider (outside the top bans)".bold().yellow());
+    for (champion, note) in hints {
+        println!("  {} {}: {}", "⚠️".red(), champion.bold(), note);
+    }
+    println!();
+}
+
+pub fn display_class_threats(threats: Vec<BanRecommendation>) {
+    if threats.is_empty() {
+        return;
+    }
+
+    println!("{}", "Threat by Class".bold().yellow());
+
+    let mut rows = vec![];
+    for threat in &threats {
+        rows.push(ClassThreatRow {
+            class: threat.champion_name.clone(),
+            faced: format!("{:.1}% of games", threat.frequency),
+            win_rate: format!("{:.1}% (n={})", threat.win_rate * 100.0, threat.times_faced),
+            score: format!("{:.2}", threat.score),
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+    println!("  Same scoring as the bans, applied to enemy classes (Data Dragon primary tag)\n");
+}
+
+pub fn display_protected_bans(excluded: Vec<BanRecommendation>, protected: &ProtectList) {
+    if excluded.is_empty() {
+        return;
+    }
+
+    println!("{}", "🛡️  Protected Champions (excluded from bans)".bold().green());
+    for rec in &excluded {
+        println!(
+            "  {} — score {:.2}, faced in {} games, {:.1}% win rate ({})",
+            rec.champion_name.bold(),
+            rec.score,
+            rec.times_faced,
+            rec.win_rate * 100.0,
+            protected.reason(&rec.champion_name).unwrap_or("protected")
+        );
+    }
+    println!("  These would otherwise rank as bans; use --protect-top 0 or edit your protect list to include them\n");
+}
+
+fn pick_source_note(pick: &PickBans) -> String {
+    if pick.fallback {
+        format!(
+            "only {} games on {} (< {}), using your whole history",
+            pick.games_on_pick, pick.champion, MIN_PICK_GAMES
+        )
+    } else {
+        format!("from your {} games on {}", pick.games_on_pick, pick.champion)
+    }
+}
+
+pub fn display_pick_bans(pick: &PickBans) {
+    println!("\n{}", format!("🎯 Bans when playing {}", pick.champion).bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    if pick.fallback {
+        println!("{} {}\n", "⚠️".yellow(), pick_source_note(pick).yellow());
+    } else {
+        println!("{}\n", pick_source_note(pick));
+    }
+
+    if pick.recommendations.is_empty() {
+        println!("{}\n", "No ban recommendations available (not enough data)".yellow());
+        return;
+    }
+
+    let mut rows = vec![];
+    for (idx, rec) in pick.recommendations.iter().enumerate() {
+        rows.push(PickBanRow {
+            rank: format!("#{}", idx + 1),
+            champion: rec.champion_name.clone(),
+            role: format_roles(&rec.roles),
+            win_rate: format!("{:.1}% (n={})", rec.win_rate * 100.0, rec.times_faced),
+            score: format!("{:.2}", rec.score),
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}\n", table);
+}
+
+pub fn display_pick_matrix(picks: &[PickBans], top_n: usize) {
+    if picks.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🎯 BAN MATRIX (your top picks)".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut builder = Builder::new();
+    let mut header = vec!["rank".to_string()];
+    header.extend(picks.iter().map(|p| {
+        if p.fallback {
+            format!("{} ({}g*)", p.champion, p.games_on_pick)
+        } else {
+            format!("{} ({}g)", p.champion, p.games_on_pick)
+        }
+    }));
+    builder.push_record(header);
+
+    for rank in 0..top_n {
+        let mut record = vec![format!("#{}", rank + 1)];
+        record.extend(picks.iter().map(|p| {
+            p.recommendations
+                .get(rank)
+                .map(|r| format!("{} ({:.2})", r.champion_name, r.score))
+                .unwrap_or_else(|| "-".to_string())
+        }));
+        builder.push_record(record);
+    }
+
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    if picks.iter().any(|p| p.fallback) {
+        println!(
+            "\n* fewer than {} games on this champion, bans use your whole history",
+            MIN_PICK_GAMES
+        );
+    }
+    println!();
+}
+
+fn display_ban_explanations(recommendations: &[BanRecommendation], strategy: &dyn ScoringStrategy) {
+    println!("{}", "Score Breakdown".bold().yellow());
+
+    for (idx, rec) in recommendations.iter().enumerate() {
+        println!(
+            "\n  {} {} — score {:.2} ({})",
+            format!("#{}", idx + 1).bold(),
+            rec.champion_name.bold(),
+            rec.score,
+            strategy.name()
+        );
+        for component in &rec.components {
+            println!("    {:<16} {:+.3}", component.label, component.value);
+        }
+        println!(
+            "    {:<16} {}{:.2} ({})",
+            "role factor",
+            if rec.score < 0.0 && rec.role_factor > 0.0 { "÷" } else { "×" },
+            rec.role_factor,
+            format_roles(&rec.roles)
+        );
+
+        let lost: Vec<&str> = rec
+            .matches
+            .iter()
+            .filter(|(_, won)| !won)
+            .map(|(id, _)| id.as_str())
+            .collect();
+        let won: Vec<&str> = rec
+            .matches
+            .iter()
+            .filter(|(_, won)| *won)
+            .map(|(id, _)| id.as_str())
+            .collect();
+        if !lost.is_empty() {
+            println!("    {:<16} {}", "lost in".red(), lost.join(", "));
+        }
+        if !won.is_empty() {
+            println!("    {:<16} {}", "won in".green(), won.join(", "));
+        }
+    }
+
+    println!();
+}
+
+fn format_roles(roles: &[(String, usize)]) -> String {
+    if roles.is_empty() {
+        return "-".to_string();
+    }
+
+    roles
+        .iter()
+        .map(|(role, count)| format!("{} {}", role_label(role), count))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 pub fn display_error(error: &str) {
     eprintln!("{} {}", "❌ Error:".red().bold(), error);
 }
 
+pub fn display_warning(message: &str) {
+    println!("{} {}", "⚠️".yellow(), message);
+}
+
 pub fn display_info(message: &str) {
     println!("{} {}", "ℹ️".cyan(), message);
 }
@@ -112,9 +535,20 @@ pub fn display_success(message: &str) {
     println!("{} {}", "✓".green(), message);
 }
 
-pub fn display_match_history(matches: Vec<(usize, String, bool, Vec<String>)>) {
+/// One row of the match history table
+pub struct HistoryEntry<'a> {
+    pub number: usize,
+    pub champion: String,
+    pub won: bool,
+    pub enemies: Vec<String>,
+    pub flow: Option<&'a GameFlow>,
+    pub metrics: GameMetrics,
+}
+
+pub fn display_match_history(matches: Vec<HistoryEntry>) {
     let total_matches = matches.len();
-    let wins = matches.iter().filter(|(_, _, won, _)| *won).count();
+    let wins = matches.iter().filter(|m| m.won).count();
+    let has_flows = matches.iter().any(|m| m.flow.is_some());
     let losses = total_matches - wins;
     let win_rate = (wins as f64 / total_matches as f64) * 100.0;
 
@@ -127,25 +561,38 @@ pub fn display_match_history(matches: Vec<(usize, String, bool, Vec<String>)>) {
         win_rate);
 
     let mut rows = vec![];
-    for (number, champion, won, enemies) in matches {
-        let result = if won {
+    for entry in matches {
+        let result = if entry.won {
             "WIN".green().to_string()
         } else {
             "LOSS".red().to_string()
         };
 
-        let enemies_str = enemies.join(", ");
+        let enemies_str = entry.enemies.join(", ");
 
         rows.push(MatchRow {
-            number: format!("{}", number),
-            champion,
+            number: format!("{}", entry.number),
+            champion: entry.champion,
             result,
+            kill_participation: format!("{:.0}%", entry.metrics.kill_participation * 100.0),
+            damage_share: format!("{:.0}%", entry.metrics.damage_share * 100.0),
+            gold_share: format!("{:.0}%", entry.metrics.gold_share * 100.0),
+            vision_per_min: format!("{:.1}", entry.metrics.vision_per_min),
+            cs_per_min: format!("{:.1}", entry.metrics.cs_per_min),
+            deaths: format!("{}", entry.metrics.deaths),
             enemies: enemies_str,
+            flow: entry
+                .flow
+                .map(|f| format!("{} {}", f.sparkline(12), colorize_shape(f.shape)))
+                .unwrap_or_else(|| "-".to_string()),
         });
     }
 
     let mut table = Table::new(rows);
     table.with(Style::rounded());
+    if !has_flows {
+        table.with(Disable::column(ByColumnName::new("flow")));
+    }
     println!("{}\n", table);
 }
 
@@ -201,3 +648,951 @@ pub fn display_ally_analysis(allies: Vec<AllyAnalysis>) {
 
     println!();
 }
+
+fn format_signed(value: f64) -> String {
+    let text = format!("{:+.0}", value);
+    if value > 0.0 {
+        text.green().to_string()
+    } else if value < 0.0 {
+        text.red().to_string()
+    } else {
+        text
+    }
+}
+
+pub fn display_matchups(matchups: Vec<MatchupStats>, lane_by_opponent: Vec<(String, LaneDiffs)>) {
+    if matchups.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "⚔️  LANE MATCHUPS".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut rows = vec![];
+    for matchup in &matchups {
+        rows.push(MatchupRow {
+            matchup: format!("As {} vs {}", matchup.player_champion, matchup.opponent_champion),
+            role: role_label(&matchup.role).to_string(),
+            record: format!("{}W-{}L", matchup.wins, matchup.losses()),
+            win_rate: format!("{:.1}%", matchup.win_rate() * 100.0),
+            gold_diff: format_signed(matchup.avg_gold_diff()),
+            cs_diff: format_signed(matchup.avg_cs_diff()),
+            xp_diff: format_signed(matchup.avg_xp_diff()),
+            kda: format!("{:.2}", matchup.kda()),
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• You vs the enemy in the same position, most losses first");
+    println!("• Gold/CS/XP Δ: your average end-of-game lead over your lane opponent\n");
+
+    if let Some(worst) = matchups.first().filter(|m| m.losses() > 0) {
+        println!("{}", "Toughest Matchup".bold().red());
+        println!(
+            "  As {} vs {}: {}W-{}L ({:+.0} gold, {:+.0} CS on average)",
+            worst.player_champion,
+            worst.opponent_champion,
+            worst.wins,
+            worst.losses(),
+            worst.avg_gold_diff(),
+            worst.avg_cs_diff()
+        );
+        if worst.lane.losses_behind + worst.lane.losses_ahead > 0 {
+            println!(
+                "  Timeline: {} lost behind at 15 min (lost in lane), {} lost while even or ahead (lost later)",
+                worst.lane.losses_behind, worst.lane.losses_ahead
+            );
+        }
+    }
+
+    if !lane_by_opponent.is_empty() {
+        println!("\n{}", "Lane Diffs vs Opponent".bold().yellow());
+        let rows: Vec<(String, &LaneDiffs)> = lane_by_opponent
+            .iter()
+            .map(|(opponent, lane)| (format!("vs {}", opponent), lane))
+            .collect();
+        display_lane_diffs("opponent", &rows);
+    }
+
+    println!();
+}
+
+/// Gold/XP/CS diffs at each checkpoint, plus how losses split between lane
+/// and later
+fn display_lane_diffs(label: &str, rows: &[(String, &LaneDiffs)]) {
+    let mut builder = Builder::new();
+    let mut header = vec![label.to_string(), "games".to_string()];
+    header.extend(CHECKPOINTS.iter().map(|m| format!("@{} gold/xp/cs", m)));
+    header.push("losses lane/later".to_string());
+    builder.push_record(header);
+
+    for (name, lane) in rows {
+        let mut record = vec![name.clone(), lane.games.to_string()];
+        for i in 0..CHECKPOINTS.len() {
+            record.push(match lane.average(i) {
+                Some((gold, xp, cs)) => format!(
+                    "{} / {} / {}",
+                    format_signed(gold),
+                    format_signed(xp),
+                    format_signed(cs)
+                ),
+                None => "-".to_string(),
+            });
+        }
+        record.push(format!("{} / {}", lane.losses_behind, lane.losses_ahead));
+        builder.push_record(record);
+    }
+
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+    println!("• From match timelines: you minus your lane opponent, averaged over games that reached each minute");
+    println!("• Losses lane/later: losses where you were behind in gold at 15 vs even or ahead");
+}
+
+pub fn display_backtest(results: Vec<BacktestResult>, selected_strategy: &str, top_n: usize) {
+    println!("\n{}", format!("🧪 BAN BACKTEST (top {} bans)", top_n).bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    if results.iter().all(|r| r.games_evaluated == 0) {
+        println!(
+            "{}",
+            format!(
+                "Not enough cached history (need more than {} games). Run a normal analysis first.",
+                MIN_HISTORY
+            )
+            .yellow()
+        );
+        return;
+    }
+
+    let mut rows = vec![];
+    for result in &results {
+        let strategy = if result.strategy == selected_strategy {
+            format!("▶ {}", result.strategy).bold().to_string()
+        } else {
+            format!("  {}", result.strategy)
+        };
+        let impact = format!("{:+.1}%", result.estimated_win_rate_delta * 100.0);
+        let win_rate_impact = if result.estimated_win_rate_delta > 0.0 {
+            impact.green().to_string()
+        } else if result.estimated_win_rate_delta < 0.0 {
+            impact.red().to_string()
+        } else {
+            impact
+        };
+
+        rows.push(BacktestRow {
+            strategy,
+            games: format!("{}", result.games_evaluated),
+            loss_hit_rate: format!(
+                "{}/{} ({:.1}%)",
+                result.loss_hits,
+                result.losses_evaluated,
+                result.loss_hit_rate() * 100.0
+            ),
+            coverage: format!("{:.1}%", result.coverage() * 100.0),
+            win_rate_impact,
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Interpretation".bold().yellow());
+    println!("• Each game is scored using only the games played before it");
+    println!("• Loss Hit Rate: Losses where an enemy champion was in the top {} bans", top_n);
+    println!("• Coverage: Games (won or lost) where an enemy champion was in the top {} bans", top_n);
+    println!("• Est. WR Impact: Win rate change if those games had been average games instead");
+    println!("• Role weights and the killer term are not replayed: the cache stores no teamPosition or death data");
+
+    for result in results.iter().filter(|r| r.strategy == selected_strategy) {
+        println!("\n{} {}", "Selected:".bold(), result.description);
+    }
+
+    println!();
+}
+
+fn colorize_form(form: &str) -> String {
+    form.chars()
+        .map(|c| match c {
+            'W' => "W".green().to_string(),
+            _ => "L".red().to_string(),
+        })
+        .collect()
+}
+
+pub fn display_champion_pool(pool: Vec<ChampionPoolStats>, baseline_win_rate: f64) {
+    if pool.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🏆 YOUR CHAMPION POOL".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut rows = vec![];
+    for champion in &pool {
+        let trend = match champion.trend() {
+            Some(t) if t >= 0.15 => "↑".green().to_string(),
+            Some(t) if t <= -0.15 => "↓".red().to_string(),
+            Some(_) => "→".to_string(),
+            None => "-".to_string(),
+        };
+        let verdict = champion.verdict(baseline_win_rate);
+        let verdict_label = match verdict {
+            PoolVerdict::Keep => verdict.label().green().to_string(),
+            PoolVerdict::Watch => verdict.label().yellow().to_string(),
+            PoolVerdict::Drop => verdict.label().red().to_string(),
+        };
+
+        rows.push(PoolRow {
+            champion: champion.champion.clone(),
+            games: format!("{}", champion.games_played()),
+            win_rate: format!("{:.1}%", champion.win_rate() * 100.0),
+            kda: format!("{:.2}", champion.kda()),
+            cs_per_min: format!("{:.1}", champion.avg_cs_per_min()),
+            damage_share: format!("{:.1}%", champion.avg_damage_share() * 100.0),
+            form: colorize_form(&champion.recent_form(5)),
+            trend,
+            verdict: verdict_label,
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    let lane_rows: Vec<(String, &LaneDiffs)> = pool
+        .iter()
+        .filter(|c| c.lane.games > 0)
+        .map(|c| (c.champion.clone(), &c.lane))
+        .collect();
+    if !lane_rows.is_empty() {
+        println!("\n{}", "Lane Diffs by Champion".bold().yellow());
+        display_lane_diffs("champion", &lane_rows);
+    }
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Form: Last 5 games on the champion, most recent first");
+    println!("• Trend: Latest games vs the ones before them");
+    println!(
+        "• Verdict: Keep if your adjusted win rate beats your overall {:.1}%, Drop if clearly below and not improving\n",
+        baseline_win_rate * 100.0
+    );
+
+    let with_verdict = |verdict: PoolVerdict| -> Vec<String> {
+        pool.iter()
+            .filter(|c| c.verdict(baseline_win_rate) == verdict)
+            .map(|c| c.champion.clone())
+            .collect()
+    };
+    let keep = with_verdict(PoolVerdict::Keep);
+    let drop = with_verdict(PoolVerdict::Drop);
+    if !keep.is_empty() {
+        println!("  {} {}", "Keep in your pool:".bold().green(), keep.join(", "));
+    }
+    if !drop.is_empty() {
+        println!("  {} {}", "Consider dropping:".bold().red(), drop.join(", "));
+    }
+
+    println!();
+}
+
+pub fn display_counter_picks(enemies: &[String], counters: Vec<CounterPick>) {
+    println!("\n{}", format!("🛡️  COUNTER PICKS vs {}", enemies.join(", ")).bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    if counters.is_empty() {
+        println!(
+            "{}\n",
+            "None of your champions have games against these enemies yet".yellow()
+        );
+        return;
+    }
+
+    let mut rows = vec![];
+    for (idx, counter) in counters.iter().enumerate() {
+        let basis = counter
+            .evidence
+            .iter()
+            .map(|e| {
+                format!(
+                    "{} {}-{} ({})",
+                    e.enemy,
+                    e.wins,
+                    e.games - e.wins,
+                    if e.lane { "lane" } else { "team" }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let (low, high) = counter.confidence_interval;
+
+        rows.push(CounterRow {
+            rank: format!("#{}", idx + 1),
+            champion: counter.champion.clone(),
+            record: format!("{}W-{}L", counter.wins, counter.games - counter.wins),
+            adjusted_win_rate: format!("{:.1}%", counter.adjusted_win_rate * 100.0),
+            confidence: format!("{:.0}–{:.0}%", low * 100.0, high * 100.0),
+            basis,
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Record: Your games on the champion against any of these enemies, each game counted once");
+    println!("• Basis: lane = you laned against them, team = they were on the enemy team");
+    println!("• Adj. Win Rate: Pulled toward your overall win rate when the sample is small\n");
+}
+
+pub fn display_duo_partners(partners: Vec<TeammateStats>, split: SoloDuoSplit) {
+    if partners.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🤝 DUO PARTNERS".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut rows = vec![];
+    for partner in &partners {
+        let champions = partner
+            .champions()
+            .iter()
+            .map(|(name, games)| format!("{} {}", name, games))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        rows.push(DuoRow {
+            partner: partner.riot_id.clone(),
+            games: format!("{}", partner.games),
+            win_rate: format!("{:.1}% ({}/{})", partner.win_rate() * 100.0, partner.wins, partner.games),
+            champions,
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Solo vs Duo".bold().yellow());
+    println!(
+        "  Solo: {:.1}% WR ({}/{} games)",
+        split.solo_win_rate() * 100.0,
+        split.solo_wins,
+        split.solo_games
+    );
+    println!(
+        "  Duo:  {:.1}% WR ({}/{} games)",
+        split.duo_win_rate() * 100.0,
+        split.duo_wins,
+        split.duo_games
+    );
+
+    if split.solo_games > 0 && split.duo_games > 0 {
+        let diff = (split.duo_win_rate() - split.solo_win_rate()) * 100.0;
+        if diff >= 0.0 {
+            println!("  {} You win {:.1}% more often with a duo", "📈".green(), diff);
+        } else {
+            println!("  {} You win {:.1}% less often with a duo", "📉".red(), -diff);
+        }
+    }
+
+    println!();
+}
+
+pub fn display_repeat_opponents(opponents: Vec<OpponentStats>) {
+    if opponents.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "👀 REPEAT OPPONENTS".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut rows = vec![];
+    for opponent in &opponents {
+        let champions = opponent
+            .champions()
+            .iter()
+            .map(|(name, games)| format!("{} {}", name, games))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let last_encounter = match opponent.last_seen {
+            Some(last_seen) => format!("{} ({})", last_seen.format("%Y-%m-%d"), opponent.last_match_id),
+            None => opponent.last_match_id.clone(),
+        };
+        let hint = match opponent.main_champion() {
+            Some(main) if opponent.is_nemesis() => format!("💀 ban {}", main).red().to_string(),
+            _ => "-".to_string(),
+        };
+
+        rows.push(OpponentRow {
+            player: opponent.riot_id.clone(),
+            met: format!("{}", opponent.games),
+            record: format!("{}W-{}L", opponent.wins, opponent.losses()),
+            champions,
+            last_encounter,
+            hint,
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Enemy players you met more than once, nemeses first");
+    println!("• Nemesis: beat you more often than not — consider banning their main\n");
+}
+
+pub fn display_synergy(
+    with_you: Vec<PairStats>,
+    ally_pairs: Vec<PairStats>,
+    baseline_win_rate: f64,
+    limit: usize,
+) {
+    if with_you.is_empty() && ally_pairs.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🔗 CHAMPION SYNERGY".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    for (title, pairs) in [("Your champion + ally", &with_you), ("Ally + ally", &ally_pairs)] {
+        if pairs.is_empty() {
+            continue;
+        }
+
+        let mut rows = vec![];
+        for pair in pairs.iter().take(limit) {
+            let label_pair = match pair.kind {
+                PairKind::WithYou => format!("your {} + {}", pair.first, pair.second),
+                _ => format!("{} + {}", pair.first, pair.second),
+            };
+            let significance = pair.significance(baseline_win_rate);
+            let label = format!("{:+.1}σ", significance);
+
+            rows.push(PairRow {
+                pair: label_pair,
+                games: format!("{}", pair.games),
+                win_rate: format!("{:.1}% ({}/{})", pair.win_rate() * 100.0, pair.wins, pair.games),
+                significance: if significance >= 0.0 {
+                    label.green().to_string()
+                } else {
+                    label.red().to_string()
+                },
+            });
+        }
+
+        println!("{}", title.bold());
+        let mut table = Table::new(rows);
+        table.with(Style::rounded());
+        println!("{}\n", table);
+    }
+
+    println!("{}", "Analysis".bold().yellow());
+    println!(
+        "• vs usual: Standard errors above/below your overall {:.1}% win rate (|σ| ≥ 2 is notable)",
+        baseline_win_rate * 100.0
+    );
+    println!("• Sorted by how far each pair is from your usual results, good or bad\n");
+}
+
+pub fn display_enemy_pairs(pairs: Vec<PairStats>, total_games: usize, baseline_win_rate: f64, limit: usize) {
+    if pairs.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "👥 ENEMY DUOS".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut rows = vec![];
+    for pair in pairs.iter().take(limit) {
+        let losses = pair.games - pair.wins;
+        rows.push(EnemyPairRow {
+            pair: format!("{} + {}", pair.first, pair.second),
+            lanes: pair_label(pair.kind).to_string(),
+            met: format!(
+                "{} ({:.1}%)",
+                pair.games,
+                pair.games as f64 / total_games.max(1) as f64 * 100.0
+            ),
+            record: format!("{}W-{}L", pair.wins, losses),
+            loss_rate: format!("{:.1}%", (1.0 - pair.win_rate()) * 100.0),
+            threat: if is_dangerous(pair, baseline_win_rate) {
+                "⚠️ dangerous".red().to_string()
+            } else {
+                "-".to_string()
+            },
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Enemy bot lanes (ADC + support) and jungle/mid duos met more than once");
+    println!("• Dangerous: you lose to the pair clearly more than usual — banning either half breaks it up\n");
+}
+
+pub fn display_archetypes(archetypes: Vec<ArchetypeStats>, baseline_win_rate: f64) {
+    if archetypes.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🧩 TEAM COMPOSITIONS".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut rows = vec![];
+    for archetype in &archetypes {
+        let significance = archetype.significance(baseline_win_rate);
+        let label = format!("{:+.1}σ", significance);
+
+        rows.push(ArchetypeRow {
+            comp: archetype.archetype.clone(),
+            side: archetype.side.label().to_string(),
+            games: format!("{}", archetype.games),
+            win_rate: format!("{:.1}% ({}/{})", archetype.win_rate() * 100.0, archetype.wins, archetype.games),
+            significance: if significance >= 0.0 {
+                label.green().to_string()
+            } else {
+                label.red().to_string()
+            },
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Comps are classified from Data Dragon champion tags (primary class)");
+
+    if let Some(worst) = archetypes
+        .iter()
+        .filter(|a| a.significance(baseline_win_rate) < 0.0)
+        .min_by(|a, b| {
+            a.significance(baseline_win_rate)
+                .partial_cmp(&b.significance(baseline_win_rate))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    {
+        let verb = match worst.side {
+            TeamSide::Enemy => "vs",
+            TeamSide::Ally => "with",
+        };
+        println!(
+            "• You lose {:.0}% of games {} {} comps",
+            (1.0 - worst.win_rate()) * 100.0,
+            verb,
+            worst.archetype
+        );
+    }
+    if let Some(best) = archetypes
+        .iter()
+        .filter(|a| a.significance(baseline_win_rate) > 0.0)
+        .max_by(|a, b| {
+            a.significance(baseline_win_rate)
+                .partial_cmp(&b.significance(baseline_win_rate))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+    {
+        let verb = match best.side {
+            TeamSide::Enemy => "vs",
+            TeamSide::Ally => "with",
+        };
+        println!(
+            "• You win {:.0}% of games {} {} comps",
+            best.win_rate() * 100.0,
+            verb,
+            best.archetype
+        );
+    }
+
+    println!();
+}
+
+pub fn display_damage_profiles(damage: &DamageTracker, baseline_win_rate: f64) {
+    if damage.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🗡️  DAMAGE PROFILES".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut builder = Builder::new();
+    builder.push_record(["", "your team", "enemy team"]);
+    for profile in DamageProfile::ALL {
+        let cell = |side: TeamSide| {
+            let record = damage.record(side, profile);
+            if record.games == 0 {
+                "-".to_string()
+            } else {
+                let text = format!(
+                    "{:.1}% ({}W-{}L)",
+                    record.win_rate() * 100.0,
+                    record.wins,
+                    record.losses()
+                );
+                if record.significance(baseline_win_rate) <= -1.0 {
+                    text.red().to_string()
+                } else if record.significance(baseline_win_rate) >= 1.0 {
+                    text.green().to_string()
+                } else {
+                    text
+                }
+            }
+        };
+        builder.push_record([profile.label().to_string(), cell(TeamSide::Ally), cell(TeamSide::Enemy)]);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Your mix vs enemy mix".bold());
+    let mut builder = Builder::new();
+    let mut header = vec!["you \\ enemy".to_string()];
+    header.extend(DamageProfile::ALL.iter().map(|p| p.label().to_string()));
+    builder.push_record(header);
+    for own in DamageProfile::ALL {
+        let mut record = vec![own.label().to_string()];
+        record.extend(DamageProfile::ALL.iter().map(|enemy| {
+            let r = damage.matchup(own, *enemy);
+            if r.games == 0 {
+                "-".to_string()
+            } else {
+                format!("{}W-{}L", r.wins, r.losses())
+            }
+        }));
+        builder.push_record(record);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Teams are classified by damage dealt to champions: AD-heavy (≥65% physical), AP-heavy (≥55% magic), else Mixed");
+    println!("• Red/green: clearly below/above your overall {:.1}% win rate\n", baseline_win_rate * 100.0);
+}
+
+pub fn display_deaths(deaths: &DeathTracker) {
+    let total = deaths.total_deaths();
+    if deaths.games() == 0 {
+        return;
+    }
+
+    println!("\n{}", "💀 DEATHS".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+    println!(
+        "{} deaths in {} games ({:.1} per game)\n",
+        total,
+        deaths.games(),
+        total as f64 / deaths.games() as f64
+    );
+    if total == 0 {
+        return;
+    }
+
+    let share = |count: usize| format!("{} ({:.0}%)", count, count as f64 / total as f64 * 100.0);
+
+    let mut builder = Builder::new();
+    builder.push_record(["phase", "deaths"]);
+    for (phase, count) in deaths.by_phase() {
+        builder.push_record([phase.label().to_string(), share(count)]);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    let mut builder = Builder::new();
+    builder.push_record(["map region", "deaths"]);
+    for (region, count) in deaths.by_region() {
+        builder.push_record([region.label().to_string(), share(count)]);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    let mut builder = Builder::new();
+    builder.push_record(["killer", "deaths"]);
+    for (killer, count) in deaths.by_killer().into_iter().take(8) {
+        builder.push_record([killer, share(count)]);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Death map (your base bottom-left)".bold());
+    let heatmap = deaths.heatmap();
+    let max = heatmap.iter().flatten().copied().max().unwrap_or(0).max(1);
+    let width = heatmap[0].len();
+    println!("┌{}┐", "─".repeat(width));
+    for row in heatmap {
+        let line: String = row
+            .iter()
+            .map(|&count| match count * 4 / max {
+                _ if count == 0 => "·".dimmed().to_string(),
+                0 => "░".yellow().to_string(),
+                1 => "▒".yellow().to_string(),
+                2 => "▓".red().to_string(),
+                _ => "█".red().to_string(),
+            })
+            .collect();
+        println!("│{}│", line);
+    }
+    println!("└{}┘", "─".repeat(width));
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• From match timelines; red side games are flipped so your base is always bottom-left");
+    println!("• Shading: ░ ▒ ▓ █ from fewest to most deaths in that spot");
+    println!("• {}: deaths to towers, minions or monsters", EXECUTED);
+    println!("• Champions behind many of your deaths add a killer term to weighted ban scores\n");
+}
+
+fn colorize_shape(shape: GameShape) -> String {
+    match shape {
+        GameShape::Stomp => shape.label().bold().to_string(),
+        GameShape::Comeback => shape.label().green().to_string(),
+        GameShape::Throw => shape.label().red().to_string(),
+        GameShape::Close => shape.label().to_string(),
+    }
+}
+
+pub fn display_game_flows(flows: Vec<&GameFlow>) {
+    if flows.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "📉 GAME FLOW".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let mut builder = Builder::new();
+    builder.push_record(["shape", "games", "wins", "losses"]);
+    for (shape, wins, losses) in shape_counts(&flows) {
+        if wins + losses == 0 {
+            continue;
+        }
+        builder.push_record([
+            colorize_shape(shape),
+            (wins + losses).to_string(),
+            wins.to_string(),
+            losses.to_string(),
+        ]);
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    let (ahead, converted) = lead_conversion(&flows, LEAD_MINUTE, BIG_LEAD);
+    let thrown = thrown_leads(&flows, LEAD_MINUTE, BIG_LEAD);
+    let comebacks = comebacks_from(&flows, LEAD_MINUTE, BIG_LEAD);
+    let biggest_throw = flows.iter().filter(|f| !f.won).map(|f| f.max_lead()).max().unwrap_or(0);
+    let biggest_comeback = flows.iter().filter(|f| f.won).map(|f| f.max_deficit()).min().unwrap_or(0);
+
+    println!();
+    if ahead > 0 {
+        println!(
+            "• Up {:+}k at {} min in {} games, won {} of them",
+            BIG_LEAD / 1000,
+            LEAD_MINUTE,
+            ahead,
+            converted
+        );
+    }
+    if thrown > 0 {
+        println!(
+            "• {}",
+            format!("You threw {} games where you were +{}k at {}", thrown, BIG_LEAD / 1000, LEAD_MINUTE).red()
+        );
+    }
+    if comebacks > 0 {
+        println!(
+            "• {}",
+            format!("You came back in {} games from -{}k at {}", comebacks, BIG_LEAD / 1000, LEAD_MINUTE).green()
+        );
+    }
+    if biggest_throw > 0 {
+        println!("• Biggest lead lost: {:+} team gold", biggest_throw);
+    }
+    if biggest_comeback < 0 {
+        println!("• Biggest deficit overcome: {:+} team gold", biggest_comeback);
+    }
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Win probability is estimated from your team's gold lead each minute of the timeline");
+    println!("• Stomp: one-sided throughout; comeback: won after being clearly behind; throw: lost after being clearly ahead\n");
+}
+
+pub fn display_objectives(objectives: Vec<(Objective, ObjectiveStats)>, baseline_win_rate: f64) {
+    if objectives.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🐉 OBJECTIVE CONTROL".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let format_rate = |rate: Option<f64>| match rate {
+        Some(rate) => format!("{:.1}%", rate * 100.0),
+        None => "-".to_string(),
+    };
+
+    let mut rows = vec![];
+    for (objective, stats) in &objectives {
+        let significance = stats.significance(baseline_win_rate);
+        let label = format!("{:+.1}σ", significance);
+        rows.push(ObjectiveRow {
+            objective: objective.label().to_string(),
+            control: format!("{:.0}% ({}/{})", stats.control_rate() * 100.0, stats.secured, stats.games()),
+            win_rate_secured: format_rate(stats.win_rate_secured()),
+            win_rate_conceded: format_rate(stats.win_rate_conceded()),
+            significance: if significance >= 0.0 {
+                label.green().to_string()
+            } else {
+                label.red().to_string()
+            },
+        });
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• Taken: games where your team got it, out of games where either team did");
+    println!(
+        "• vs usual: win rate when taken, in standard errors from your overall {:.1}%",
+        baseline_win_rate * 100.0
+    );
+    println!("• Elder dragon and exact dragon soul need --timeline; without it soul means 4 dragons\n");
+}
+
+pub fn display_metrics(by_role: Vec<(String, MetricAverages)>, by_champion: Vec<(String, MetricAverages)>) {
+    if by_role.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "📋 PERFORMANCE METRICS".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let table_for = |label: &str, entries: &[(String, MetricAverages)]| {
+        let mut builder = Builder::new();
+        builder.push_record([label, "games", "KP", "dmg%", "gold%", "vis/min", "cs/min", "deaths"]);
+        for (name, averages) in entries {
+            let m = averages.average();
+            builder.push_record([
+                name.clone(),
+                averages.games.to_string(),
+                format!("{:.0}%", m.kill_participation * 100.0),
+                format!("{:.1}%", m.damage_share * 100.0),
+                format!("{:.1}%", m.gold_share * 100.0),
+                format!("{:.2}", m.vision_per_min),
+                format!("{:.1}", m.cs_per_min),
+                format!("{:.1}", m.deaths),
+            ]);
+        }
+        let mut table = builder.build();
+        table.with(Style::rounded());
+        table
+    };
+
+    let roles: Vec<(String, MetricAverages)> = by_role
+        .into_iter()
+        .map(|(role, averages)| (role_label(&role).to_string(), averages))
+        .collect();
+    println!("{}", "By Role".bold());
+    println!("{}\n", table_for("role", &roles));
+    println!("{}", "By Champion".bold());
+    println!("{}", table_for("champion", &by_champion));
+
+    println!("\n{}", "Analysis".bold().yellow());
+    println!("• KP: kill participation, your kills + assists over your team's kills");
+    println!("• dmg% / gold%: your share of your team's damage to champions and gold earned\n");
+}
+
+pub fn display_items(champions: Vec<ChampionItems>, limit: usize) {
+    if champions.is_empty() {
+        return;
+    }
+
+    println!("\n{}", "🛡️  ITEMIZATION".bold().cyan());
+    println!("{}\n", "=".repeat(60).cyan());
+
+    let record_cell = |wins: usize, games: usize| {
+        format!("{:.0}% ({}W-{}L)", wins as f64 / games.max(1) as f64 * 100.0, wins, games - wins)
+    };
+
+    for champion in champions.iter().take(limit) {
+        println!("{}", format!("{} ({} games)", champion.champion, champion.games).bold());
+
+        let mut builder = Builder::new();
+        builder.push_record(["", "choice", "games", "win rate"]);
+        let sections = [
+            ("Core build", champion.top_builds(3)),
+            ("First item", champion.top_first_items(3)),
+            ("Boots", champion.top_boots(3)),
+        ];
+        for (kind, entries) in &sections {
+            for (i, (choice, record)) in entries.iter().enumerate() {
+                builder.push_record([
+                    if i == 0 { kind.to_string() } else { String::new() },
+                    choice.clone(),
+                    record.games.to_string(),
+                    record_cell(record.wins, record.games),
+                ]);
+            }
+        }
+        let mut table = builder.build();
+        table.with(Style::rounded());
+        println!("{}", table);
+
+        // First items and boots against the enemy team's damage mix
+        let mut builder = Builder::new();
+        let mut header = vec!["vs enemy damage".to_string()];
+        header.extend(DamageProfile::ALL.iter().map(|p| p.label().to_string()));
+        builder.push_record(header);
+        for (choice, _) in champion.top_first_items(3).into_iter().chain(champion.top_boots(3)) {
+            let mut record = vec![choice.clone()];
+            record.extend(DamageProfile::ALL.iter().map(|profile| {
+                let r = champion.against_profile(&choice, *profile);
+                if r.games == 0 {
+                    "-".to_string()
+                } else {
+                    record_cell(r.wins, r.games)
+                }
+            }));
+            builder.push_record(record);
+        }
+        let mut table = builder.build();
+        table.with(Style::rounded());
+        println!("{}", table);
+
+        let against = champion.against_opponents(2);
+        if !against.is_empty() {
+            println!("  vs lane opponent:");
+            for (item, opponent, record) in against.iter().take(5) {
+                let line = format!("    {} into {}: {}", item, opponent, record_cell(record.wins, record.games));
+                if record.wins * 2 < record.games {
+                    println!("{}", line.red());
+                } else {
+                    println!("{}", line);
+                }
+            }
+        }
+        println!();
+    }
+
+    println!("{}", "Analysis".bold().yellow());
+    println!(
+        "• Core build: your first {} completed items, in purchase order with --timeline, else inventory slot order",
+        CORE_ITEMS
+    );
+    println!("• First item needs the purchase order for accuracy; without --timeline the first filled slot is used\n");
+}
@@ -16,6 +16,9 @@ pub enum AppError {
     #[error("Player not found: {0}")]
     PlayerNotFound(String),
 
+    #[error("Player not found among the participants of match {0}, skipping it")]
+    PlayerNotInMatch(String),
+
     #[error("No ranked games found for this player")]
     NoRankedGames,
 
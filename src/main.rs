@@ -6,12 +6,33 @@ mod display;
 mod error;
 mod rate_limit;
 
+use analysis::archetypes::{ArchetypeTracker, ChampionClasses, CLASSES};
+use analysis::backtest::{run_backtest, BacktestSettings};
+use analysis::champion_pool::ChampionPoolTracker;
 use analysis::champion_stats::ChampionStatsTracker;
+use analysis::counters::suggest_counters;
+use analysis::damage::DamageTracker;
+use analysis::deaths::DeathTracker;
+use analysis::enemy_pairs::EnemyPairTracker;
+use analysis::items::{ItemCatalog, ItemTracker};
+use analysis::lane_diffs::lane_diffs;
+use analysis::metrics::{GameMetrics, MetricsTracker};
+use analysis::matchups::{find_lane_opponent, MatchupTracker};
+use analysis::objectives::ObjectiveTracker;
+use analysis::opponents::OpponentTracker;
+use analysis::picks::{PickBans, PickTracker, MIN_PICK_GAMES};
+use analysis::protection::ProtectList;
+use analysis::recency::{decay_weight, parse_half_life};
 use analysis::recommender::BanRecommender;
+use analysis::roles::{parse_role, RoleWeights};
+use analysis::scoring::{strategy_from_name, ScoreWeights, ScoringContext, STRATEGY_NAMES};
+use analysis::synergy::{PairKind, SynergyTracker};
+use analysis::teammates::{TeammateTracker, DUO_MIN_GAMES};
+use analysis::win_probability::{game_flow, GameFlow};
 use api::client::RiotApiClient;
 use clap::Parser;
 use config::Config;
-use display::output::{display_ban_recommendations, display_error, display_info, display_success, display_match_history, display_ally_analysis};
+use display::output::{display_ban_recommendations, display_error, display_info, display_success, display_warning, display_match_history, display_ally_analysis, display_matchups, display_backtest, display_protected_bans, display_pick_bans, display_pick_matrix, display_champion_pool, display_counter_picks, display_duo_partners, display_repeat_opponents, display_synergy, display_enemy_pairs, display_class_threats, display_archetypes, display_damage_profiles, display_deaths, display_game_flows, display_objectives, display_metrics, display_items, HistoryEntry};
 use error::AppError;
 use indicatif::ProgressBar;
 
@@ -21,6 +42,10 @@ struct MatchResult {
     player_champion: String,
     won: bool,
     enemy_champions: Vec<String>,
+    ended_at: Option<chrono::DateTime<chrono::Utc>>,
+    recency_weight: f64,
+    flow: Option<GameFlow>, // only with --timeline
+    metrics: GameMetrics,
 }
 
 #[derive(Parser, Debug)]
@@ -57,6 +82,77 @@ struct Args {
     /// Queue type: ranked, normal, all (default: ranked)
     #[arg(short, long, default_value = "ranked")]
     queue: String,
+
+    /// Your role: top, jungle, mid, bot, support
+    /// (default: inferred from your teamPosition in each game)
+    #[arg(long)]
+    role: Option<String>,
+
+    /// Ban weights for lane opponents, junglers and other roles
+    #[arg(long, default_value = "1.0,0.8,0.5")]
+    role_weights: String,
+
+    /// Minimum games against a champion before it can be recommended as a ban
+    #[arg(long, default_value = "2")]
+    min_games: usize,
+
+    /// Ban scoring strategy: weighted, loss-contribution, winrate-delta, frequency
+    /// (default: BAN_STRATEGY from .env, else weighted)
+    #[arg(long)]
+    strategy: Option<String>,
+
+    /// Weights for the weighted strategy: frequency,win_rate,recency[,killer]
+    /// (default: BAN_WEIGHTS from .env, else 0.4,0.5,0.1,0.2)
+    #[arg(long)]
+    weights: Option<String>,
+
+    /// Recency half-life: a game this old counts half as much (e.g. 14d, 36h, 2w)
+    #[arg(long, default_value = "14d")]
+    half_life: String,
+
+    /// Also apply the recency decay to frequency and win rate, not just the recency term
+    #[arg(long)]
+    decay_all: bool,
+
+    /// Show the score breakdown and the matches behind each ban recommendation
+    #[arg(long)]
+    explain: bool,
+
+    /// Replay cached match history to measure how well the ban strategies
+    /// would have done (no API requests)
+    #[arg(long)]
+    backtest: bool,
+
+    /// Champions never to recommend as bans, comma-separated
+    /// (added to PROTECTED_CHAMPIONS from .env)
+    #[arg(long)]
+    protect: Option<String>,
+
+    /// Also protect your N most played champions (0 to disable)
+    #[arg(long, default_value = "2")]
+    protect_top: usize,
+
+    /// Pick the champions protected by --protect-top from your mastery
+    /// instead of the analyzed games
+    #[arg(long)]
+    protect_mastery: bool,
+
+    /// Recommend bans for the games where you played this champion
+    #[arg(long)]
+    pick: Option<String>,
+
+    /// Show pick-specific bans for your three most played champions
+    #[arg(long)]
+    pick_matrix: bool,
+
+    /// Suggest counter picks from your pool against these enemies, comma-separated
+    #[arg(long, value_delimiter = ',')]
+    vs: Vec<String>,
+
+    /// Also fetch match timelines for minute-by-minute analysis
+    /// (one extra request per uncached match)
+    #[arg(long)]
+    timeline: bool,
 }
 
 fn main() {
@@ -75,8 +171,98 @@ fn run(args: Args) -> Result<(), AppError> {
         config.region = region;
     }
 
+    let player_role = match args.role.as_deref() {
+        Some(role) => Some(parse_role(role).ok_or_else(|| {
+            AppError::ConfigError(format!(
+                "Unknown role '{}' (expected top, jungle, mid, bot or support)",
+                role
+            ))
+        })?),
+        None => None,
+    };
+    let role_weights = RoleWeights::parse(&args.role_weights).ok_or_else(|| {
+        AppError::ConfigError(format!(
+            "Invalid --role-weights '{}' (expected three non-negative numbers lane,jungle,other e.g. 1.0,0.8,0.5)",
+            args.role_weights
+        ))
+    })?;
+
+    let score_weights = match args.weights.as_ref().or(config.ban_weights.as_ref()) {
+        Some(weights) => ScoreWeights::parse(weights).ok_or_else(|| {
+            AppError::ConfigError(format!(
+                "Invalid ban weights '{}' (expected non-negative numbers frequency,win_rate,recency[,killer] e.g. 0.4,0.5,0.1)",
+                weights
+            ))
+        })?,
+        None => ScoreWeights::default(),
+    };
+    let strategy_name = args
+        .strategy
+        .clone()
+        .or_else(|| config.ban_strategy.clone())
+        .unwrap_or_else(|| "weighted".to_string());
+    let strategy = strategy_from_name(&strategy_name, score_weights).ok_or_else(|| {
+        AppError::ConfigError(format!(
+            "Unknown ban strategy '{}' (expected one of: {})",
+            strategy_name,
+            STRATEGY_NAMES.join(", ")
+        ))
+    })?;
+
+    let half_life = parse_half_life(&args.half_life).ok_or_else(|| {
+        AppError::ConfigError(format!(
+            "Invalid --half-life '{}' (expected e.g. 14d, 36h or 2w)",
+            args.half_life
+        ))
+    })?;
+
+    let mut protected = ProtectList::new();
+    if let Some(ref champions) = config.protected_champions {
+        protected.add_list(champions, "protect list");
+    }
+    if let Some(ref champions) = args.protect {
+        protected.add_list(champions, "--protect");
+    }
+
     let player_key = format!("{}#{}", args.game_name, args.tag_line);
 
+    if args.backtest {
+        let match_cache = cache::MatchCache::load(&player_key)?;
+        if match_cache.matches.is_empty() {
+            return Err(AppError::NoRankedGames);
+        }
+
+        display_info(&format!(
+            "Backtesting ban strategies on {} cached matches for {}",
+            match_cache.matches.len(),
+            player_key
+        ));
+        let undated = match_cache.matches.iter().filter(|m| m.ended_at.is_none()).count();
+        if undated > 0 {
+            display_warning(&format!(
+                "{} cached matches have no game end time and are skipped; run a normal analysis to fill it in",
+                undated
+            ));
+        }
+
+        let settings = BacktestSettings {
+            top_n: args.top_n,
+            min_games: args.min_games,
+            half_life,
+            decay_all: args.decay_all,
+            protected: protected.clone(),
+            protect_top: if args.protect_mastery { 0 } else { args.protect_top },
+        };
+        let results = STRATEGY_NAMES
+            .iter()
+            .filter_map(|name| strategy_from_name(name, score_weights))
+            .map(|s| run_backtest(&match_cache.matches, s.as_ref(), &settings))
+            .collect();
+
+        display_backtest(results, strategy.name(), args.top_n);
+        return Ok(());
+    }
+
     // Load rate limit tracker
     let mut rate_limiter = rate_limit::RequestLog::load(&player_key)?;
 
@@ -86,7 +272,7 @@ fn run(args: Args) -> Result<(), AppError> {
     } else if args.refresh && !rate_limiter.can_make_request() {
         rate_limiter.display_status();
         return Err(AppError::ApiError(
-            format!("⛔ API limit reached (50 req/day, 20 req/hour). Try again in ~1 hour or tomorrow."),
+            "⛔ API limit reached (50 req/day, 20 req/hour). Try again in ~1 hour or tomorrow.".to_string(),
         ));
     }
 
@@ -161,7 +347,7 @@ fn run(args: Args) -> Result<(), AppError> {
 
     let has_cache = match_cache.as_ref().map(|c| !c.matches.is_empty()).unwrap_or(false);
 
-    let mut all_match_ids = if has_cache && !args.refresh {
+    let all_match_ids = if has_cache && !args.refresh {
         // Smart cache: check online for new matches (IDs only - fast!)
         display_info("Step 4: Checking for new matches online...");
         let matches_count = std::cmp::min(args.matches, 100);
@@ -269,11 +455,47 @@ fn run(args: Args) -> Result<(), AppError> {
 
     display_success(&format!("Found {} matches to analyze", match_ids.len()));
 
+    // Champion classes (Data Dragon, not rate limited) for comp analysis
+    let champion_data = match client.get_champion_data() {
+        Ok(data) => Some(data),
+        Err(e) => {
+            display_warning(&format!("Could not fetch champion data, skipping comp analysis: {}", e));
+            None
+        }
+    };
+    let champion_classes = champion_data.as_ref().map(ChampionClasses::from_data);
+
+    // Item names and kinds (Data Dragon) for the itemization report
+    let item_catalog = match client.get_item_data() {
+        Ok(data) => Some(ItemCatalog::from_data(&data)),
+        Err(e) => {
+            display_warning(&format!("Could not fetch item data, skipping itemization: {}", e));
+            None
+        }
+    };
+
     // Step 5: Fetch match details with progress bar
     let pb = ProgressBar::new(match_ids.len() as u64);
     pb.set_message("Fetching match details");
     let mut tracker = ChampionStatsTracker::new();
+    let mut matchup_tracker = MatchupTracker::new();
+    let mut pick_tracker = PickTracker::new();
+    let mut pool_tracker = ChampionPoolTracker::new();
+    let mut teammate_tracker = TeammateTracker::new();
+    let mut opponent_tracker = OpponentTracker::new();
+    let mut synergy_tracker = SynergyTracker::new();
+    let mut enemy_pair_tracker = EnemyPairTracker::new();
+    let mut archetype_tracker = ArchetypeTracker::new();
+    let mut damage_tracker = DamageTracker::new();
+    let mut death_tracker = DeathTracker::new();
+    let mut objective_tracker = ObjectiveTracker::new();
+    let mut metrics_tracker = MetricsTracker::new();
+    let mut item_tracker = ItemTracker::new();
     let mut match_history = Vec::new();
+    let mut timelines_loaded = 0;
+    let mut timelines_cached = 0;
+    let mut timeline_events = 0;
+    let now = chrono::Utc::now();
 
     for (idx, match_id) in match_ids.iter().enumerate() {
         let match_data = client.get_match(match_id)?;
@@ -285,21 +507,108 @@ fn run(args: Args) -> Result<(), AppError> {
         pb.inc(1);
 
         // Find our player in the match
-        let our_player = match_data
-            .info
-            .participants
-            .iter()
-            .find(|p| p.puuid == account.puuid);
+        let our_player = match match_data.find_participant(
+            &account.puuid,
+            &args.game_name,
+            &args.tag_line,
+        ) {
+            Some(p) => p,
+            None => {
+                // Without our player we can't tell allies from enemies
+                pb.suspend(|| {
+                    display_warning(&AppError::PlayerNotInMatch(match_id.clone()).to_string())
+                });
+                continue;
+            }
+        };
+
+        // Timelines are only fetched on request; cached ones cost nothing
+        let timeline = if args.timeline {
+            let (result, fetched) = client.get_match_timeline(match_id);
+            if fetched {
+                rate_limiter.record_request();
+                rate_limiter.save().ok();
+            }
+            match result {
+                Ok(t) if t.participant_id(&our_player.puuid).is_some() => {
+                    if !fetched {
+                        timelines_cached += 1;
+                    }
+                    timelines_loaded += 1;
+                    timeline_events += t.events().count();
+                    Some(t)
+                }
+                Ok(_) => {
+                    pb.suspend(|| {
+                        display_warning(&format!("Timeline for {} does not include you, skipping it", match_id))
+                    });
+                    None
+                }
+                Err(e) => {
+                    pb.suspend(|| {
+                        display_warning(&format!("No timeline for {}: {}", match_id, e))
+                    });
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let our_team_id = our_player.team_id;
+        let won = our_player.win;
+        let player_champion = our_player.champion_name.clone();
+        let our_role = player_role.unwrap_or(our_player.team_position.as_str());
+
+        let metrics = GameMetrics::compute(
+            our_player,
+            &match_data.info.participants,
+            match_data.info.duration_minutes(),
+        );
+        metrics_tracker.add_game(our_player, &metrics);
+        pool_tracker.add_game(our_player, &metrics);
+
+        teammate_tracker.add_game(our_player, &match_data.info.participants);
+        synergy_tracker.add_game(our_player, &match_data.info.participants);
+        enemy_pair_tracker.add_game(our_player, &match_data.info.participants);
+        damage_tracker.add_game(our_player, &match_data.info.participants);
+        opponent_tracker.add_game(
+            our_player,
+            &match_data.info.participants,
+            match_id,
+            match_data.info.ended_at(),
+        );
 
-        let our_team_id = our_player.map(|p| p.team_id).unwrap_or(100);
-        let won = our_player.map(|p| p.win).unwrap_or(false);
-        let player_champion = our_player
-            .map(|p| p.champion_name.clone())
-            .unwrap_or_else(|| "Unknown".to_string());
+        if let Some(opponent) = find_lane_opponent(our_player, &match_data.info.participants) {
+            matchup_tracker.add_matchup(our_player, opponent);
+
+            if let Some(diffs) = timeline
+                .as_ref()
+                .and_then(|t| lane_diffs(t, &our_player.puuid, &opponent.puuid))
+            {
+                matchup_tracker.add_lane_diffs(our_player, opponent, &diffs);
+                pool_tracker.add_lane_diffs(our_player, &diffs);
+            }
+        }
 
         // Collect enemy champions and track allies
         let mut enemy_champions = Vec::new();
-        let recency_weight = 1.0 - (idx as f64 / match_ids.len() as f64);
+        let ended_at = match_data.info.ended_at();
+        let recency_weight = match ended_at {
+            Some(ended_at) => decay_weight(ended_at, now, half_life),
+            // No timing data: fall back to list position
+            None => 1.0 - (idx as f64 / match_ids.len() as f64),
+        };
+        let pick_stats = pick_tracker.add_game(&player_champion, won, recency_weight);
+        if let Some(ref classes) = champion_classes {
+            archetype_tracker.add_game(
+                our_player,
+                &match_data.info.participants,
+                classes,
+                recency_weight,
+                match_id,
+            );
+        }
 
         // Track enemy champions and allies
         for participant in &match_data.info.participants {
@@ -310,8 +619,19 @@ fn run(args: Args) -> Result<(), AppError> {
                     participant.champion_name.clone(),
                     won,
                     recency_weight,
+                    &participant.team_position,
+                    role_weights.weight(our_role, &participant.team_position),
+                    match_id,
                 );
-            } else if participant.puuid != account.puuid {
+                pick_stats.add_champion_encounter(
+                    participant.champion_name.clone(),
+                    won,
+                    recency_weight,
+                    &participant.team_position,
+                    role_weights.weight(our_role, &participant.team_position),
+                    match_id,
+                );
+            } else if participant.puuid != our_player.puuid {
                 // Ally champion (same team but not us)
                 tracker.add_ally_encounter(
                     participant.champion_name.clone(),
@@ -321,16 +641,43 @@ fn run(args: Args) -> Result<(), AppError> {
             }
         }
 
+        if let Some(ref timeline) = timeline {
+            for killer in death_tracker.add_game(timeline, our_player, &match_data.info.participants) {
+                tracker.add_kill_on_player(&killer);
+                pick_stats.add_kill_on_player(&killer);
+            }
+        }
+
+        objective_tracker.add_game(our_team_id, won, &match_data.info.teams, timeline.as_ref());
+        if let Some(ref catalog) = item_catalog {
+            item_tracker.add_game(our_player, &match_data.info.participants, catalog, timeline.as_ref());
+        }
+
+        let flow = timeline
+            .as_ref()
+            .and_then(|t| game_flow(t, our_player, &match_data.info.participants));
+
         match_history.push(MatchResult {
             match_number: idx + 1,
             player_champion,
             won,
             enemy_champions,
+            ended_at,
+            recency_weight,
+            flow,
+            metrics,
         });
     }
 
     pb.finish_with_message("✓ Match data fetched");
 
+    if args.timeline {
+        display_info(&format!(
+            "Loaded {} timelines ({} from cache, {} events)",
+            timelines_loaded, timelines_cached, timeline_events
+        ));
+    }
+
     // Update cache with new matches
     if match_cache.is_none() {
         match_cache = Some(cache::MatchCache::new(&player_key, &config.region));
@@ -353,7 +700,8 @@ fn run(args: Args) -> Result<(), AppError> {
                 champion: m.player_champion.clone(),
                 won: m.won,
                 enemies: m.enemy_champions.clone(),
-                timestamp: chrono::Utc::now(),
+                timestamp: m.ended_at.unwrap_or(now),
+                ended_at: m.ended_at,
             })
             .collect();
 
@@ -361,32 +709,197 @@ fn run(args: Args) -> Result<(), AppError> {
         let _ = cache_mut.save(); // Save to disk silently
     }
 
+    if match_history.is_empty() {
+        return Err(AppError::NoRankedGames);
+    }
+
+    // Protect the player's own pool
+    if args.protect_mastery && args.protect_top > 0 {
+        match client.get_top_masteries(&account.puuid, args.protect_top) {
+            Ok(masteries) => {
+                rate_limiter.record_request();
+                rate_limiter.save().ok();
+
+                for mastery in masteries {
+                    let key = mastery.champion_id.to_string();
+                    let info = champion_data
+                        .as_ref()
+                        .and_then(|champions| champions.data.values().find(|c| c.key == key));
+                    if let Some(info) = info {
+                        protected.add(
+                            &info.id,
+                            &format!("your mastery ({} pts)", mastery.champion_points),
+                        );
+                    }
+                }
+            }
+            Err(e) => display_warning(&format!("Could not fetch champion mastery: {}", e)),
+        }
+    } else {
+        let played: Vec<String> = match_history
+            .iter()
+            .map(|m| m.player_champion.clone())
+            .collect();
+        protected.add_top_played(&played, args.protect_top);
+    }
+
     // Step 6: Generate recommendations (use actual analyzed matches, not total)
     let stats = tracker.get_stats();
-    let total_games_analyzed = match_ids.len();
-    let recommendations =
-        BanRecommender::get_recommendations(stats, total_games_analyzed, args.top_n);
+    let games: Vec<(bool, f64)> = match_history
+        .iter()
+        .map(|m| (m.won, m.recency_weight))
+        .collect();
+    let scoring_ctx = ScoringContext::new(&stats, &games, args.decay_all);
+    let (bannable, protected_stats) = BanRecommender::partition_protected(stats, &protected);
+    let mut recommendations = BanRecommender::get_recommendations(
+        bannable,
+        strategy.as_ref(),
+        &scoring_ctx,
+        args.top_n,
+        args.min_games,
+    );
+    let nemesis_hints = opponent_tracker.nemesis_ban_hints();
+    BanRecommender::annotate(&mut recommendations, &nemesis_hints);
+    // Nemesis mains outside the top N are still worth showing under the bans,
+    // unless they are protected
+    let unranked_hints = BanRecommender::unmatched_hints(&recommendations, &nemesis_hints, &protected);
+    BanRecommender::annotate(&mut recommendations, &death_tracker.killer_ban_hints(0.1));
+    BanRecommender::annotate(
+        &mut recommendations,
+        &enemy_pair_tracker.dangerous_pair_hints(scoring_ctx.baseline_win_rate()),
+    );
+    let excluded = BanRecommender::get_recommendations(
+        protected_stats,
+        strategy.as_ref(),
+        &scoring_ctx,
+        args.top_n,
+        args.min_games,
+    );
 
     // Display results
     let history_data: Vec<_> = match_history
         .iter()
-        .map(|m| {
-            (
-                m.match_number,
-                m.player_champion.clone(),
-                m.won,
-                m.enemy_champions.clone(),
-            )
+        .map(|m| HistoryEntry {
+            number: m.match_number,
+            champion: m.player_champion.clone(),
+            won: m.won,
+            enemies: m.enemy_champions.clone(),
+            flow: m.flow.as_ref(),
+            metrics: m.metrics,
         })
         .collect();
 
     display_match_history(history_data);
-    display_ban_recommendations(recommendations, &summoner.name);
+    display_game_flows(match_history.iter().filter_map(|m| m.flow.as_ref()).collect());
+    display_ban_recommendations(
+        recommendations,
+        &summoner.name,
+        player_role,
+        strategy.as_ref(),
+        &unranked_hints,
+        args.explain,
+    );
+    display_protected_bans(excluded, &protected);
+
+    if champion_classes.is_some() {
+        let class_stats = archetype_tracker.class_stats();
+        let class_ctx = ScoringContext::new(&class_stats, &games, args.decay_all);
+        let class_threats = BanRecommender::get_recommendations(
+            class_stats,
+            strategy.as_ref(),
+            &class_ctx,
+            CLASSES.len(),
+            args.min_games,
+        );
+        display_class_threats(class_threats);
+    }
+
+    // Pick-specific bans
+    let pick_bans = |pick: &str| -> PickBans {
+        let mut pick_protected = protected.clone();
+        pick_protected.add(pick, "your pick");
+
+        let (champion, games_on_pick, history) = match pick_tracker.get(pick) {
+            Some(h) => (h.champion.clone(), h.games.len(), Some(h)),
+            None => (pick.to_string(), 0, None),
+        };
+        let (stats, pick_games, fallback) = match history {
+            Some(h) if h.games.len() >= MIN_PICK_GAMES => (h.tracker.get_stats(), &h.games, false),
+            _ => (tracker.get_stats(), &games, true),
+        };
+
+        let ctx = ScoringContext::new(&stats, pick_games, args.decay_all);
+        let (bannable, _) = BanRecommender::partition_protected(stats, &pick_protected);
+        PickBans {
+            champion,
+            games_on_pick,
+            fallback,
+            recommendations: BanRecommender::get_recommendations(
+                bannable,
+                strategy.as_ref(),
+                &ctx,
+                args.top_n,
+                args.min_games,
+            ),
+        }
+    };
+
+    if let Some(ref pick) = args.pick {
+        display_pick_bans(&pick_bans(pick));
+    }
+    if args.pick_matrix {
+        let matrix: Vec<PickBans> = pick_tracker
+            .top_picks(3)
+            .iter()
+            .map(|h| pick_bans(&h.champion))
+            .collect();
+        display_pick_matrix(&matrix, args.top_n);
+    }
+
+    display_archetypes(
+        archetype_tracker.get_archetypes(scoring_ctx.baseline_win_rate()),
+        scoring_ctx.baseline_win_rate(),
+    );
+    display_damage_profiles(&damage_tracker, scoring_ctx.baseline_win_rate());
+    display_deaths(&death_tracker);
+    display_enemy_pairs(
+        enemy_pair_tracker.get_pairs(),
+        match_history.len(),
+        scoring_ctx.baseline_win_rate(),
+        10,
+    );
+    display_matchups(matchup_tracker.get_matchups(), matchup_tracker.lane_by_opponent());
+    display_repeat_opponents(opponent_tracker.repeat_opponents());
+    display_champion_pool(pool_tracker.get_pool(), scoring_ctx.baseline_win_rate());
+    display_metrics(metrics_tracker.by_role(), metrics_tracker.by_champion());
+    display_items(item_tracker.get_champions(2), 5);
+
+    if !args.vs.is_empty() {
+        let enemies: Vec<String> = args.vs.iter().map(|e| e.trim().to_string()).collect();
+        let counters = suggest_counters(
+            &enemies,
+            &pick_tracker,
+            &matchup_tracker,
+            scoring_ctx.baseline_win_rate(),
+        );
+        display_counter_picks(&enemies, counters);
+    }
 
     // Analyze and display ally performance
     let ally_stats = tracker.get_ally_stats();
     let ally_analysis = BanRecommender::analyze_allies(ally_stats, 1); // Show allies with 1+ games
     display_ally_analysis(ally_analysis);
+    display_objectives(objective_tracker.get_objectives(), scoring_ctx.baseline_win_rate());
+    display_synergy(
+        synergy_tracker.get_pairs(PairKind::WithYou, scoring_ctx.baseline_win_rate()),
+        synergy_tracker.get_pairs(PairKind::Allies, scoring_ctx.baseline_win_rate()),
+        scoring_ctx.baseline_win_rate(),
+        10,
+    );
+    display_duo_partners(
+        teammate_tracker.duo_partners(DUO_MIN_GAMES),
+        teammate_tracker.solo_duo_split(DUO_MIN_GAMES),
+    );
 
     // Display API usage stats
     rate_limiter.display_status();
@@ -93,6 +93,7 @@ impl RequestLog {
         self.last_request = Utc::now();
     }
 
+    #[allow(dead_code)]
     pub fn get_remaining(&self) -> (u32, u32) {
         (
             MAX_REQUESTS_PER_2MIN - self.requests_per_2min,
@@ -108,7 +109,6 @@ impl RequestLog {
     }
 
     pub fn display_status(&self) {
-        let (rem_2min, rem_sec) = self.get_remaining();
         let (reset_2min, reset_sec) = self.get_reset_times();
         let now = Utc::now();
 